@@ -1,14 +1,35 @@
-import type { Permit } from "secretjs"
+import type { Permission, Permit } from "secretjs"
 
 export const usePermits = () => {
   async function getOwnerPermit(onSuccess: (permit: Permit) => void) {
     const secretClientProxy = useSecretClientProxy()
+    const connectedWalletAndClientStore = useConnectedWalletAndClientStore()
     const { CONTRACT_ADDRESS } = useAppRuntimeConfig()
 
+    // Permits not signed for the current epoch are rejected once all permits were revoked
+    let permitName = "owner"
+    const permissions: Permission[] = ["owner"]
+    const { keplrAccount } = connectedWalletAndClientStore
+    if (keplrAccount) {
+      const queryResult = await secretClientProxy.queryContract({
+        get_permit_epoch: {
+          address: keplrAccount.address,
+        },
+      }) as {
+        permit_epoch: number,
+      } | string
+      if (typeof queryResult !== "string" && queryResult.permit_epoch > 0) {
+        // New name so permits signed for earlier epochs are not reused
+        permitName = `owner@${queryResult.permit_epoch}`
+        // Contract specific permission, unknown to secretjs
+        permissions.push({ permit_epoch: queryResult.permit_epoch } as unknown as Permission)
+      }
+    }
+
     return await secretClientProxy.getPermit({
-      permitName: "owner",
+      permitName: permitName,
       allowedContracts: [CONTRACT_ADDRESS],
-      permissions: ["owner"],
+      permissions: permissions,
       onSuccess: onSuccess,
     })
   }
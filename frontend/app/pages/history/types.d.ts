@@ -1,6 +1,7 @@
 
 export type UserCountUpdateHistoryEntry = {
   user_addr: string
-  count_change: number
+  count_change: string
+  resulting_count: string | null
   created_at_in_ms: number
 }
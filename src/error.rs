@@ -0,0 +1,28 @@
+use cosmwasm_std::{StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Rate limited, retry after {} (unix time in seconds)", retry_after.seconds())]
+    RateLimited { retry_after: Timestamp },
+
+    #[error("Too many count changes in this block, retry after block {retry_after_height}")]
+    BlockCountChangeLimitReached { retry_after_height: u64 },
+
+    // Count or one of the tallies would go over 64 bits
+    #[error("Count overflow")]
+    CountOverflow,
+}
+
+// Lets `?` work where `StdResult` is still returned, typed errors become message text there
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Std(std_err) => std_err,
+            _ => StdError::generic_err(err.to_string()),
+        }
+    }
+}
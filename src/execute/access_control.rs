@@ -0,0 +1,171 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use crate::msg::ExecuteMsg;
+use crate::state::access_control::{AccessControlManager, AccessList, AccessMode};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub fn try_set_access_mode(deps: DepsMut, env: Env, info: MessageInfo, mode: AccessMode) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageAccessLists)?;
+
+    let old_mode = AccessControlManager::get_access_mode(deps.storage)?;
+    AccessControlManager::set_access_mode(deps.storage, mode)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::SetAccessMode,
+        target: None,
+        old_value: Some(to_audit_value(&old_mode)?),
+        new_value: Some(to_audit_value(&mode)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("access mode updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_add_to_access_list(deps: DepsMut, env: Env, info: MessageInfo, list: AccessList, addresses: Vec<String>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageAccessLists)?;
+
+    let addrs = addresses.iter().map(|a| deps.api.addr_validate(a)).collect::<StdResult<Vec<Addr>>>()?;
+    AccessControlManager::add_to_list(deps.storage, list, &addrs)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::AddToAccessList,
+        target: Some(to_audit_value(&list)?),
+        old_value: None,
+        new_value: Some(to_audit_value(&addrs)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("access list updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_remove_from_access_list(deps: DepsMut, env: Env, info: MessageInfo, list: AccessList, addresses: Vec<String>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageAccessLists)?;
+
+    let addrs = addresses.iter().map(|a| deps.api.addr_validate(a)).collect::<StdResult<Vec<Addr>>>()?;
+    AccessControlManager::remove_from_list(deps.storage, list, &addrs)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::RemoveFromAccessList,
+        target: Some(to_audit_value(&list)?),
+        old_value: None,
+        new_value: Some(to_audit_value(&addrs)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("access list updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_create_invite_code(deps: DepsMut, env: Env, info: MessageInfo, code: String, max_uses: Option<u32>) -> StdResult<Response> {
+    let issued_by_manager = RolesManager::has_capability(deps.storage, &info.sender, Capability::ManageAccessLists)?;
+    // Users can only invite others when they are allowed themselves
+    if !issued_by_manager {
+        AccessControlManager::ensure_allowed(deps.storage, &info.sender)?;
+    }
+
+    let uses = AccessControlManager::create_invite_code(deps.storage, &env, &info.sender, code, max_uses.unwrap_or(1), issued_by_manager)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::CreateInviteCode,
+        // Code itself not logged, those reading the audit log could redeem it
+        target: None,
+        old_value: None,
+        new_value: Some(to_audit_value(&uses)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("invite code created successfully");
+    Ok(Response::default())
+}
+
+pub fn try_redeem_invite_code(deps: DepsMut, _env: Env, info: MessageInfo, code: String) -> StdResult<Response> {
+    AccessControlManager::redeem_invite_code(deps.storage, &info.sender, code)?;
+
+    deps.api.debug("invite code redeemed successfully");
+    Ok(Response::default())
+}
+
+/// Checked by `execute_dispatch` before any handler runs
+pub fn ensure_msg_allowed(storage: &dyn Storage, sender_addr: &Addr, msg: &ExecuteMsg) -> StdResult<()> {
+    match msg {
+        ExecuteMsg::Increment { .. } |
+        ExecuteMsg::AddBookmarkNumber { .. } |
+        ExecuteMsg::UpdateBookmarkedNumber { .. } => AccessControlManager::ensure_allowed(storage, sender_addr),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+
+    #[test]
+    fn access_mode_n_lists_work() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: None, mark_history_as_public: None, counter_id: None, target: None };
+
+        assert!(try_set_access_mode(deps.as_mut(), mock_env(), mock_info("creator", &[]), AccessMode::Denylist).is_err());
+        assert!(try_add_to_access_list(deps.as_mut(), mock_env(), mock_info("creator", &[]), AccessList::Denylist, vec!["spammer".to_string()]).is_err());
+
+        let _res = try_set_access_mode(deps.as_mut(), mock_env(), mock_info("owner", &[]), AccessMode::Denylist)?;
+        let _res = try_add_to_access_list(deps.as_mut(), mock_env(), mock_info("owner", &[]), AccessList::Denylist, vec!["spammer".to_string()])?;
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("spammer"), &increment_msg).is_err());
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("creator"), &increment_msg).is_ok());
+        // Other messages are not affected
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("spammer"), &ExecuteMsg::RedeemInviteCode { code: "code".to_string() }).is_ok());
+
+        let _res = try_remove_from_access_list(deps.as_mut(), mock_env(), mock_info("owner", &[]), AccessList::Denylist, vec!["spammer".to_string()])?;
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("spammer"), &increment_msg).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invite_codes_work() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: None, mark_history_as_public: None, counter_id: None, target: None };
+        let _res = try_set_access_mode(deps.as_mut(), mock_env(), mock_info("owner", &[]), AccessMode::Allowlist)?;
+
+        // Not invited yet
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("user1"), &increment_msg).is_err());
+        assert!(try_create_invite_code(deps.as_mut(), mock_env(), mock_info("user1", &[]), "user1_code".to_string(), None).is_err());
+
+        let _res = try_create_invite_code(deps.as_mut(), mock_env(), mock_info("owner", &[]), "owner_code".to_string(), Some(10))?;
+        let _res = try_redeem_invite_code(deps.as_mut(), mock_env(), mock_info("user1", &[]), "owner_code".to_string())?;
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("user1"), &increment_msg).is_ok());
+
+        // Invited user can invite others
+        let _res = try_create_invite_code(deps.as_mut(), mock_env(), mock_info("user1", &[]), "user1_code".to_string(), None)?;
+        let _res = try_redeem_invite_code(deps.as_mut(), mock_env(), mock_info("user2", &[]), "user1_code".to_string())?;
+        assert!(ensure_msg_allowed(deps.as_ref().storage, &Addr::unchecked("user2"), &increment_msg).is_ok());
+        // Single use
+        assert!(try_redeem_invite_code(deps.as_mut(), mock_env(), mock_info("user3", &[]), "user1_code".to_string()).is_err());
+
+        // should leave a trace
+        assert_eq!(
+            AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, None)?,
+            vec![AuditLogEntry{
+                actor_addr: Addr::unchecked("user1"),
+                action: AuditAction::CreateInviteCode,
+                target: None,
+                old_value: None,
+                new_value: Some("1".to_string()),
+                created_at: mock_env().block.time,
+            }],
+        );
+
+        Ok(())
+    }
+}
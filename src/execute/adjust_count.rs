@@ -0,0 +1,95 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::Int64;
+use crate::state::counters::{CountersManager, ManageAuthority};
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use crate::state::user_count_update_history::{UserCountUpdateHistoryManager, UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
+use super::privileged_action;
+
+pub fn try_adjust_count(deps: DepsMut, env: Env, info: MessageInfo, count_change: i32, counter_id: Option<String>) -> Result<Response, ContractError> {
+    let sender_address = info.sender.clone();
+    let authority = CountersManager::ensure_can_manage(deps.storage, &CountersManager::resolve_counter_id(counter_id.clone()), &sender_address)?;
+
+    let action = PrivilegedAction::AdjustCount { count_change, counter_id };
+    match authority {
+        ManageAuthority::Capability |
+        ManageAuthority::CounterManager => privileged_action::perform_or_queue(deps, env, sender_address, action),
+        // Owners act on their own data only, no global multisig or timelock
+        ManageAuthority::PersonalCounterOwner => privileged_action::perform(deps, env, sender_address, action),
+    }
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_adjust_count(deps: DepsMut, env: Env, sender_address: Addr, count_change: i32, counter_id: Option<String>) -> Result<Response, ContractError> {
+    let resolved_counter_id = CountersManager::resolve_counter_id(counter_id.clone());
+    let mut state = CountersManager::get_state(deps.storage, &resolved_counter_id)?;
+    let old_count = state.count;
+    state.count = old_count.checked_add(Int64::from(count_change)).ok_or(ContractError::CountOverflow)?;
+
+    CountersManager::save_state(deps.storage, &resolved_counter_id, &state)?;
+
+    UserCountUpdateHistoryManager::add_entry(deps.storage, &env, &resolved_counter_id, UserCountUpdateHistoryEntry{
+        user_addr: sender_address.clone(),
+        count_change: Int64::from(count_change),
+        resulting_count: Some(state.count),
+        kind: UserCountUpdateHistoryEntryKind::Adjustment,
+        created_at: env.block.time,
+        marked_as_public_at: None,
+    }, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: sender_address,
+        action: AuditAction::AdjustCount,
+        target: counter_id,
+        old_value: Some(to_audit_value(&old_count.i64())?),
+        new_value: Some(to_audit_value(&state.count.i64())?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("count adjusted successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{StdResult, Uint64};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::State;
+    use crate::state::counters::{DEFAULT_COUNTER_ID};
+
+    #[test]
+    fn adjust_count_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        assert!(try_adjust_count(deps.as_mut(), mock_env(), mock_info("creator", &[]), -3, None).is_err());
+        let _res = try_adjust_count(deps.as_mut(), mock_env(), mock_info("owner", &[]), -3, None)?;
+
+        // Not counted as increment or reset
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?, State {
+            count: Int64::new(14),
+            count_increment_count: Uint64::new(0),
+            count_reset_count: Uint64::new(0),
+        });
+        let history_entries = UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, Addr::unchecked("owner"), 0, 1, false, None);
+        assert_eq!(history_entries[0].count_change, Int64::new(-3));
+        assert_eq!(history_entries[0].kind, UserCountUpdateHistoryEntryKind::Adjustment);
+
+        // Over 32 bits is fine
+        assert!(try_adjust_count(deps.as_mut(), mock_env(), mock_info("owner", &[]), i32::MAX, None).is_ok());
+        assert!(try_adjust_count(deps.as_mut(), mock_env(), mock_info("owner", &[]), i32::MAX, None).is_ok());
+        let mut state = CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?;
+        state.count = Int64::new(i64::MAX);
+        CountersManager::save_state(deps.as_mut().storage, DEFAULT_COUNTER_ID, &state)?;
+        assert_eq!(
+            try_adjust_count(deps.as_mut(), mock_env(), mock_info("owner", &[]), 1, None),
+            Err(ContractError::CountOverflow),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage, Timestamp};
+use crate::state::CONFIG;
+use crate::state::announcements::{Announcement, AnnouncementSeverity, AnnouncementsManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub struct AnnouncementPayload {
+    pub title: String,
+    pub body: String,
+    pub severity: AnnouncementSeverity,
+    pub visible_from_in_ms: Option<u64>,
+    pub visible_until_in_ms: Option<u64>,
+}
+
+pub fn try_post(deps: DepsMut, env: Env, info: MessageInfo, payload: AnnouncementPayload) -> StdResult<Response> {
+    ensure_contract_manager(deps.storage, &info)?;
+
+    let announcement = Announcement {
+        title: payload.title,
+        body: payload.body,
+        severity: payload.severity,
+        visible_from: payload.visible_from_in_ms.map(timestamp_from_ms),
+        visible_until: payload.visible_until_in_ms.map(timestamp_from_ms),
+        created_at: env.block.time,
+        updated_at: env.block.time,
+    };
+    announcement.validate()?;
+    let announcement_id = AnnouncementsManager::add_entry(deps.storage, &env, announcement.clone(), None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::PostAnnouncement,
+        target: Some(announcement_id),
+        old_value: None,
+        new_value: Some(to_audit_value(&announcement)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("announcement posted successfully");
+    Ok(Response::default())
+}
+
+/// Replaces all content of the announcement
+pub fn try_edit(deps: DepsMut, env: Env, info: MessageInfo, announcement_id: String, payload: AnnouncementPayload) -> StdResult<Response> {
+    ensure_contract_manager(deps.storage, &info)?;
+
+    let old_announcement = load_announcement(deps.storage, &announcement_id)?;
+    let announcement = Announcement {
+        title: payload.title,
+        body: payload.body,
+        severity: payload.severity,
+        visible_from: payload.visible_from_in_ms.map(timestamp_from_ms),
+        visible_until: payload.visible_until_in_ms.map(timestamp_from_ms),
+        created_at: old_announcement.created_at,
+        updated_at: env.block.time,
+    };
+    announcement.validate()?;
+    AnnouncementsManager::update_entry(deps.storage, &announcement_id, &announcement, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::EditAnnouncement,
+        target: Some(announcement_id),
+        old_value: Some(to_audit_value(&old_announcement)?),
+        new_value: Some(to_audit_value(&announcement)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("announcement edited successfully");
+    Ok(Response::default())
+}
+
+/// Hides the announcement from now on, kept for editing later
+pub fn try_expire(deps: DepsMut, env: Env, info: MessageInfo, announcement_id: String) -> StdResult<Response> {
+    ensure_contract_manager(deps.storage, &info)?;
+
+    let old_announcement = load_announcement(deps.storage, &announcement_id)?;
+    let mut announcement = old_announcement.clone();
+    announcement.visible_until = Some(env.block.time);
+    // Keep it valid when it was scheduled for the future
+    if announcement.visible_from.is_some_and(|visible_from| visible_from > env.block.time) {
+        announcement.visible_from = Some(env.block.time);
+    }
+    announcement.updated_at = env.block.time;
+    AnnouncementsManager::update_entry(deps.storage, &announcement_id, &announcement, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::ExpireAnnouncement,
+        target: Some(announcement_id),
+        old_value: Some(to_audit_value(&old_announcement.visible_until)?),
+        new_value: Some(to_audit_value(&announcement.visible_until)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("announcement expired successfully");
+    Ok(Response::default())
+}
+
+pub fn try_delete(deps: DepsMut, env: Env, info: MessageInfo, announcement_id: String) -> StdResult<Response> {
+    ensure_contract_manager(deps.storage, &info)?;
+
+    let old_announcement = load_announcement(deps.storage, &announcement_id)?;
+    AnnouncementsManager::remove_entry(deps.storage, &announcement_id, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::DeleteAnnouncement,
+        target: Some(announcement_id),
+        old_value: Some(to_audit_value(&old_announcement)?),
+        new_value: None,
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("announcement deleted successfully");
+    Ok(Response::default())
+}
+
+fn ensure_contract_manager(storage: &dyn Storage, info: &MessageInfo) -> StdResult<()> {
+    let config = CONFIG.load(storage)?;
+    if info.sender != config.contract_manager {
+        return Err(StdError::generic_err("Only the contract manager can manage announcements"));
+    }
+
+    Ok(())
+}
+
+fn timestamp_from_ms(ms: u64) -> Timestamp {
+    Timestamp::from_nanos(ms.saturating_mul(1_000_000))
+}
+
+fn load_announcement(storage: &dyn Storage, announcement_id: &String) -> StdResult<Announcement> {
+    AnnouncementsManager::get_entry(storage, announcement_id, None).
+        ok_or_else(|| StdError::generic_err("Announcement not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+
+    fn build_payload(title: &str) -> AnnouncementPayload {
+        AnnouncementPayload {
+            title: title.to_string(),
+            body: "Body".to_string(),
+            severity: AnnouncementSeverity::Warning,
+            visible_from_in_ms: None,
+            visible_until_in_ms: None,
+        }
+    }
+
+    #[test]
+    fn manage_announcements_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), init_msg)?;
+
+        assert!(try_post(deps.as_mut(), env.clone(), mock_info("creator", &[]), build_payload("Title")).is_err());
+        assert!(try_post(deps.as_mut(), env.clone(), mock_info("owner", &[]), build_payload("")).is_err());
+        let _res = try_post(deps.as_mut(), env.clone(), mock_info("owner", &[]), build_payload("Title"))?;
+        let (entries, _) = AnnouncementsManager::get_visible_entries(deps.as_ref().storage, &env, 0, 10, false, None)?;
+        let announcement_id = entries[0].0.clone();
+
+        let _res = try_edit(deps.as_mut(), env.clone(), mock_info("owner", &[]), announcement_id.clone(), build_payload("New Title"))?;
+        assert_eq!(load_announcement(deps.as_ref().storage, &announcement_id)?.title, "New Title".to_string());
+
+        let _res = try_expire(deps.as_mut(), env.clone(), mock_info("owner", &[]), announcement_id.clone())?;
+        assert_eq!(AnnouncementsManager::get_visible_entries(deps.as_ref().storage, &env, 0, 10, false, None)?.1, 0);
+
+        assert!(try_delete(deps.as_mut(), env.clone(), mock_info("creator", &[]), announcement_id.clone()).is_err());
+        let _res = try_delete(deps.as_mut(), env.clone(), mock_info("owner", &[]), announcement_id.clone())?;
+        assert!(load_announcement(deps.as_ref().storage, &announcement_id).is_err());
+
+        Ok(())
+    }
+}
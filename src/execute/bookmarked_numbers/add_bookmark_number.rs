@@ -1,7 +1,13 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::error::ContractError;
+use crate::state::{CONFIG};
+use crate::state::rate_limit::{RateLimitManager};
 use crate::state::bookmarked_numbers::{BookmarkedNumbersManager, BookmarkedNumberEntry};
 
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, number: i32, memo_text: String, mark_entry_as_public: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Response> {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, number: i32, memo_text: String, mark_entry_as_public: bool, suffix_4_test: Option<&[u8]>) -> Result<Response, ContractError> {
+    CONFIG.load(deps.storage)?.counter_policy.ensure_memo_text_allowed(&memo_text)?;
+    RateLimitManager::check_n_record_bookmark_creation(deps.storage, &env, &info.sender)?;
+
     BookmarkedNumbersManager::add_one_entry(deps.storage, &env, BookmarkedNumberEntry{
         owner_addr: info.sender.clone(),
         number,
@@ -24,7 +30,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, number: i32, memo_tex
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::{Addr, Coin, Uint128};
+    use cosmwasm_std::{Addr, Coin, StdResult, Uint128};
     use cosmwasm_std::testing::{mock_dependencies_with_balance, mock_env, mock_info};
     use crate::msg::{InstantiateMsg};
     use nanoid::nanoid;
@@ -109,6 +115,15 @@ mod tests {
 
         // endregion public entry
 
+        // region memo text too long
+
+        let mut config = CONFIG.load(deps.as_ref().storage)?;
+        config.counter_policy.max_memo_length = Some(4);
+        CONFIG.save(deps.as_mut().storage, &config)?;
+        assert!(execute(deps.as_mut(), mock_env(), info.clone(), 5, memo_text.clone(), true, Some(suffix_4_test)).is_err());
+
+        // endregion memo text too long
+
         Ok(())
     }
 }
@@ -1,2 +1,3 @@
 pub mod add_bookmark_number;
 pub(in crate::execute) mod update_bookmark_number_entry;
+pub(in crate::execute) mod moderate_bookmark_number_entry;
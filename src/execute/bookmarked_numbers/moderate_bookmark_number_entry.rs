@@ -0,0 +1,94 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::state::bookmarked_numbers::{BookmarkedNumbersManager};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub fn hide(deps: DepsMut, env: Env, info: MessageInfo, entry_id: String, reason: String, suffix_4_test: Option<&[u8]>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ModerateContent)?;
+
+    let new_value = to_audit_value(&reason)?;
+    BookmarkedNumbersManager::hide_one_entry(deps.storage, &env, &info.sender, entry_id.clone(), reason, suffix_4_test)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::HideBookmarkedNumberEntry,
+        target: Some(entry_id),
+        old_value: None,
+        new_value: Some(new_value),
+        created_at: env.block.time,
+    }, suffix_4_test)?;
+
+    Ok(Response::default())
+}
+
+pub fn restore(deps: DepsMut, env: Env, info: MessageInfo, entry_id: String, suffix_4_test: Option<&[u8]>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ModerateContent)?;
+
+    let old_moderation_record = BookmarkedNumbersManager::get_moderation_record(deps.storage, &entry_id, suffix_4_test);
+    BookmarkedNumbersManager::restore_one_entry(deps.storage, entry_id.clone(), suffix_4_test)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::RestoreBookmarkedNumberEntry,
+        target: Some(entry_id),
+        old_value: old_moderation_record.map(|r| to_audit_value(&r.hidden_reason)).transpose()?,
+        new_value: None,
+        created_at: env.block.time,
+    }, suffix_4_test)?;
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{Addr, Timestamp};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use nanoid::nanoid;
+    use crate::msg::{InstantiateMsg};
+    use crate::state::utils::{get_generated_ulid};
+    use crate::state::bookmarked_numbers::{BookmarkedNumberEntry};
+    use crate::state::roles::{Role};
+
+    #[test]
+    fn hide_n_restore_works() -> StdResult<()> {
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        RolesManager::grant_role(deps.as_mut().storage, &Addr::unchecked("moderator"), Role::Moderator)?;
+
+        BookmarkedNumbersManager::add_one_entry(deps.as_mut().storage, &env, BookmarkedNumberEntry{
+            owner_addr: Addr::unchecked("creator"),
+            number: 1,
+            memo_text: "abusive".to_string(),
+            marked_as_public_at: Some(Timestamp::from_nanos(0)),
+
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }, Some(suffix_4_test))?;
+        let entry_id = get_generated_ulid(1, &env)?;
+
+        // Owner cannot moderate
+        assert!(hide(deps.as_mut(), env.clone(), mock_info("creator", &[]), entry_id.clone(), "abuse".to_string(), Some(suffix_4_test)).is_err());
+
+        let _res = hide(deps.as_mut(), env.clone(), mock_info("moderator", &[]), entry_id.clone(), "abuse".to_string(), Some(suffix_4_test))?;
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 0);
+        assert!(BookmarkedNumbersManager::get_moderation_record(deps.as_ref().storage, &entry_id, Some(suffix_4_test)).is_some());
+
+        // Contract manager can moderate too
+        let _res = restore(deps.as_mut(), env.clone(), mock_info("owner", &[]), entry_id.clone(), Some(suffix_4_test))?;
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 1);
+        assert!(BookmarkedNumbersManager::get_moderation_record(deps.as_ref().storage, &entry_id, Some(suffix_4_test)).is_none());
+
+        assert_eq!(AuditLogManager::get_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 2);
+        // Reason JSON encoded like other values
+        assert_eq!(AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, false, Some(suffix_4_test))?[0].new_value, Some("\"abuse\"".to_string()));
+        assert_eq!(AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, Some(suffix_4_test))?[0].old_value, Some("\"abuse\"".to_string()));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::state::{CONFIG};
 use crate::state::bookmarked_numbers::{BookmarkedNumbersManager, UpdateOneEntryPayload};
 
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, entry_id: String, memo_text: String, mark_entry_as_public: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Response> {
+    CONFIG.load(deps.storage)?.counter_policy.ensure_memo_text_allowed(&memo_text)?;
+
     BookmarkedNumbersManager::update_one_entry(deps.storage, &env, &info, UpdateOneEntryPayload{
         entry_id: entry_id.clone(),
         memo_text: memo_text.clone(),
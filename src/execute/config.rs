@@ -0,0 +1,165 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use crate::state::{CONFIG, CounterPolicy, RATE_LIMIT_POLICY, RateLimitPolicy, COUNT_CHANGE_APPROVAL_THRESHOLD, PERMIT_POLICY, PermitPolicy};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use super::privileged_action;
+
+pub fn try_update_config(deps: DepsMut, env: Env, info: MessageInfo, counter_policy: CounterPolicy) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::UpdateConfig { counter_policy }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_update_config(deps: DepsMut, env: Env, actor_addr: Addr, counter_policy: CounterPolicy) -> StdResult<Response> {
+    counter_policy.validate()?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_counter_policy = config.counter_policy.clone();
+    config.counter_policy = counter_policy;
+    CONFIG.save(deps.storage, &config)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::UpdateConfig,
+        target: None,
+        old_value: Some(to_audit_value(&old_counter_policy)?),
+        new_value: Some(to_audit_value(&config.counter_policy)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("config updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_update_rate_limit_policy(deps: DepsMut, env: Env, info: MessageInfo, rate_limit_policy: RateLimitPolicy) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::UpdateRateLimitPolicy { rate_limit_policy }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_update_rate_limit_policy(deps: DepsMut, env: Env, actor_addr: Addr, rate_limit_policy: RateLimitPolicy) -> StdResult<Response> {
+    let old_rate_limit_policy = RATE_LIMIT_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    RATE_LIMIT_POLICY.save(deps.storage, &rate_limit_policy)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::UpdateRateLimitPolicy,
+        target: None,
+        old_value: Some(to_audit_value(&old_rate_limit_policy)?),
+        new_value: Some(to_audit_value(&rate_limit_policy)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("rate limit policy updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_update_permit_policy(deps: DepsMut, env: Env, info: MessageInfo, permit_policy: PermitPolicy) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::UpdatePermitPolicy { permit_policy }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_update_permit_policy(deps: DepsMut, env: Env, actor_addr: Addr, permit_policy: PermitPolicy) -> StdResult<Response> {
+    let old_permit_policy = PERMIT_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    PERMIT_POLICY.save(deps.storage, &permit_policy)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::UpdatePermitPolicy,
+        target: None,
+        old_value: Some(to_audit_value(&old_permit_policy)?),
+        new_value: Some(to_audit_value(&permit_policy)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("permit policy updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_set_count_change_approval_threshold(deps: DepsMut, env: Env, info: MessageInfo, threshold: Option<u32>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::SetCountChangeApprovalThreshold { threshold }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_set_count_change_approval_threshold(deps: DepsMut, env: Env, actor_addr: Addr, threshold: Option<u32>) -> StdResult<Response> {
+    let old_threshold = COUNT_CHANGE_APPROVAL_THRESHOLD.may_load(deps.storage)?;
+    match threshold {
+        Some(threshold) => COUNT_CHANGE_APPROVAL_THRESHOLD.save(deps.storage, &threshold)?,
+        // Pending requests are kept, they still need to be approved or rejected
+        None => COUNT_CHANGE_APPROVAL_THRESHOLD.remove(deps.storage),
+    }
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::SetCountChangeApprovalThreshold,
+        target: None,
+        old_value: Some(to_audit_value(&old_threshold)?),
+        new_value: Some(to_audit_value(&threshold)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("count change approval threshold updated successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::Int64;
+
+    #[test]
+    fn update_config_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let counter_policy = CounterPolicy {
+            min_step: Some(Int64::new(1)),
+            max_step: Some(Int64::new(10)),
+            count_floor: Some(Int64::new(0)),
+            count_ceiling: None,
+            allow_negative_steps: false,
+            max_memo_length: Some(100),
+        };
+
+        assert!(try_update_config(deps.as_mut(), mock_env(), mock_info("creator", &[]), counter_policy.clone()).is_err());
+        // Invalid policy
+        assert!(try_update_config(deps.as_mut(), mock_env(), mock_info("owner", &[]), CounterPolicy {
+            min_step: Some(Int64::new(10)),
+            max_step: Some(Int64::new(1)),
+            ..counter_policy.clone()
+        }).is_err());
+
+        let _res = try_update_config(deps.as_mut(), mock_env(), mock_info("owner", &[]), counter_policy.clone())?;
+        assert_eq!(CONFIG.load(deps.as_ref().storage)?.counter_policy, counter_policy);
+        assert_eq!(AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, None)?[0].action, AuditAction::UpdateConfig);
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_rate_limit_policy_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let rate_limit_policy = RateLimitPolicy {
+            increment_cooldown_in_seconds: Some(60),
+            max_bookmarks_created_per_day: Some(10),
+            max_count_changes_per_block: None,
+        };
+
+        assert!(try_update_rate_limit_policy(deps.as_mut(), mock_env(), mock_info("creator", &[]), rate_limit_policy.clone()).is_err());
+        let _res = try_update_rate_limit_policy(deps.as_mut(), mock_env(), mock_info("owner", &[]), rate_limit_policy.clone())?;
+        assert_eq!(RATE_LIMIT_POLICY.load(deps.as_ref().storage)?, rate_limit_policy);
+
+        Ok(())
+    }
+}
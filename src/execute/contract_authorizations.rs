@@ -0,0 +1,70 @@
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Timestamp, WasmMsg, to_binary};
+use crate::msg::ContractAuthorizationCallbackMsg;
+use crate::state::read_grants::ReadScope;
+use crate::state::contract_authorizations::{ContractAuthorizationsManager};
+
+/// Key is sent to the contract with a callback message, since contracts cannot sign permits
+/// It is a bearer key, whoever the contract shares it with can query too
+pub fn try_authorize_contract(deps: DepsMut, env: Env, info: MessageInfo, contract_address: String, code_hash: String, scopes: Vec<ReadScope>, expires_at_in_ms: Option<u64>) -> StdResult<Response> {
+    let contract_addr = deps.api.addr_validate(&contract_address)?;
+    if scopes.is_empty() {
+        return Err(StdError::generic_err("At least one scope is required, use RevokeContractAuthorization to remove access"));
+    }
+    let expires_at = expires_at_in_ms.map(|ms| Timestamp::from_nanos(ms.saturating_mul(1_000_000)));
+    if expires_at.is_some_and(|expires_at| expires_at <= env.block.time) {
+        return Err(StdError::generic_err("Expiry must be in the future"));
+    }
+
+    let key = ContractAuthorizationsManager::authorize(deps.storage, &env, &info.sender, &contract_addr, code_hash.clone(), scopes.clone(), expires_at)?;
+    let callback_msg = ContractAuthorizationCallbackMsg::ReceiveContractAuthorization {
+        owner: info.sender.to_string(),
+        key,
+        scopes,
+        expires_at_in_ms,
+    };
+
+    deps.api.debug("contract authorized successfully");
+    Ok(Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        code_hash,
+        msg: to_binary(&callback_msg)?,
+        funds: vec![],
+    })))
+}
+
+pub fn try_revoke_contract_authorization(deps: DepsMut, _env: Env, info: MessageInfo, contract_address: String) -> StdResult<Response> {
+    let contract_addr = deps.api.addr_validate(&contract_address)?;
+    ContractAuthorizationsManager::revoke(deps.storage, &info.sender, &contract_addr)?;
+
+    deps.api.debug("contract authorization revoked successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_binary, Addr};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn authorize_contract_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        assert!(try_authorize_contract(deps.as_mut(), env.clone(), mock_info("owner", &[]), "partner_contract".to_string(), "code_hash".to_string(), vec![], None).is_err());
+        let res = try_authorize_contract(deps.as_mut(), env.clone(), mock_info("owner", &[]), "partner_contract".to_string(), "code_hash".to_string(), vec![ReadScope::Statistics], None)?;
+
+        // Key delivered to the contract is the one stored
+        let CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) = &res.messages[0].msg else {
+            panic!("Expected callback message");
+        };
+        assert_eq!(contract_addr, "partner_contract");
+        let ContractAuthorizationCallbackMsg::ReceiveContractAuthorization { key, .. } = from_binary(msg)?;
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &Addr::unchecked("owner"), &Addr::unchecked("partner_contract"), &key, ReadScope::Statistics).is_ok());
+
+        let _res = try_revoke_contract_authorization(deps.as_mut(), env.clone(), mock_info("owner", &[]), "partner_contract".to_string())?;
+        assert!(ContractAuthorizationsManager::get_authorization(deps.as_ref().storage, &Addr::unchecked("owner"), &Addr::unchecked("partner_contract")).is_none());
+
+        Ok(())
+    }
+}
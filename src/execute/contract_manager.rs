@@ -0,0 +1,136 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use crate::state::{CONFIG, PENDING_CONTRACT_MANAGER};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub fn try_propose_contract_manager(deps: DepsMut, env: Env, info: MessageInfo, address: String) -> StdResult<Response> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.contract_manager {
+        return Err(StdError::generic_err("Only the contract manager can propose a new contract manager"));
+    }
+
+    let proposed_addr = deps.api.addr_validate(&address)?;
+    if proposed_addr == config.contract_manager {
+        return Err(StdError::generic_err("Proposed address is already the contract manager"));
+    }
+    let old_pending_manager = PENDING_CONTRACT_MANAGER.may_load(deps.storage)?;
+    PENDING_CONTRACT_MANAGER.save(deps.storage, &proposed_addr)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::ProposeContractManager,
+        target: None,
+        old_value: Some(to_audit_value(&old_pending_manager)?),
+        new_value: Some(to_audit_value(&proposed_addr)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("contract manager proposed successfully");
+    Ok(Response::default())
+}
+
+pub fn try_accept_contract_manager(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let pending_manager = PENDING_CONTRACT_MANAGER.may_load(deps.storage)?;
+    match pending_manager {
+        Some(addr) if addr == info.sender => {
+            let mut config = CONFIG.load(deps.storage)?;
+            let old_manager = config.contract_manager.clone();
+            config.contract_manager = addr.clone();
+            CONFIG.save(deps.storage, &config)?;
+            PENDING_CONTRACT_MANAGER.remove(deps.storage);
+
+            AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+                actor_addr: info.sender,
+                action: AuditAction::AcceptContractManager,
+                target: None,
+                old_value: Some(to_audit_value(&old_manager)?),
+                new_value: Some(to_audit_value(&addr)?),
+                created_at: env.block.time,
+            }, None)?;
+
+            deps.api.debug("contract manager accepted successfully");
+            Ok(Response::default())
+        }
+        Some(_) => Err(StdError::generic_err("Only the pending contract manager can accept")),
+        None => Err(StdError::generic_err("No pending contract manager")),
+    }
+}
+
+pub fn try_cancel_manager_proposal(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.contract_manager {
+        return Err(StdError::generic_err("Only the contract manager can cancel a manager proposal"));
+    }
+    let pending_manager = match PENDING_CONTRACT_MANAGER.may_load(deps.storage)? {
+        Some(addr) => addr,
+        None => return Err(StdError::generic_err("No pending contract manager")),
+    };
+    PENDING_CONTRACT_MANAGER.remove(deps.storage);
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::CancelManagerProposal,
+        target: None,
+        old_value: Some(to_audit_value(&pending_manager)?),
+        new_value: None,
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("contract manager proposal cancelled successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Addr;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+
+    #[test]
+    fn propose_n_accept_contract_manager_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        // Only current manager can propose
+        assert!(try_propose_contract_manager(deps.as_mut(), mock_env(), mock_info("creator", &[]), "new_owner".to_string()).is_err());
+        let _res = try_propose_contract_manager(deps.as_mut(), mock_env(), mock_info("owner", &[]), "new_owner".to_string())?;
+        assert_eq!(PENDING_CONTRACT_MANAGER.load(deps.as_ref().storage)?, Addr::unchecked("new_owner"));
+        // Manager unchanged until accepted
+        assert_eq!(CONFIG.load(deps.as_ref().storage)?.contract_manager, Addr::unchecked("owner"));
+
+        // Only pending manager can accept
+        assert!(try_accept_contract_manager(deps.as_mut(), mock_env(), mock_info("owner", &[])).is_err());
+        let _res = try_accept_contract_manager(deps.as_mut(), mock_env(), mock_info("new_owner", &[]))?;
+        assert_eq!(CONFIG.load(deps.as_ref().storage)?.contract_manager, Addr::unchecked("new_owner"));
+        assert_eq!(PENDING_CONTRACT_MANAGER.may_load(deps.as_ref().storage)?, None);
+
+        assert_eq!(
+            AuditLogManager::get_entries(deps.as_ref().storage, 0, 10, false, None)?.iter().map(|e| e.action).collect::<Vec<_>>(),
+            vec![AuditAction::ProposeContractManager, AuditAction::AcceptContractManager],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel_manager_proposal_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        // Nothing to cancel
+        assert!(try_cancel_manager_proposal(deps.as_mut(), mock_env(), mock_info("owner", &[])).is_err());
+
+        let _res = try_propose_contract_manager(deps.as_mut(), mock_env(), mock_info("owner", &[]), "typo_owner".to_string())?;
+        assert!(try_cancel_manager_proposal(deps.as_mut(), mock_env(), mock_info("typo_owner", &[])).is_err());
+        let _res = try_cancel_manager_proposal(deps.as_mut(), mock_env(), mock_info("owner", &[]))?;
+        assert_eq!(PENDING_CONTRACT_MANAGER.may_load(deps.as_ref().storage)?, None);
+
+        // Cancelled proposal cannot be accepted
+        assert!(try_accept_contract_manager(deps.as_mut(), mock_env(), mock_info("typo_owner", &[])).is_err());
+        assert_eq!(CONFIG.load(deps.as_ref().storage)?.contract_manager, Addr::unchecked("owner"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use crate::error::ContractError;
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::count_change_request::{CountChangeRequest, CountChangeRequestManager};
+use crate::state::counters::{CountersManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use super::increment;
+
+pub fn try_approve(deps: DepsMut, env: Env, info: MessageInfo, request_id: String) -> Result<Response, ContractError> {
+    let request = load_request_for_review(&deps, &info, &request_id)?;
+    CountChangeRequestManager::remove_request(deps.storage, &request_id, &request, None)?;
+
+    // History entry only written now, as the requesting user
+    let counter_id = CountersManager::resolve_counter_id(request.counter_id.clone());
+    increment::apply_increment(deps.storage, &env, &counter_id, request.user_addr.clone(), request.count_change, request.mark_history_as_public)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::ApproveCountChangeRequest,
+        target: Some(request_id),
+        old_value: None,
+        new_value: Some(to_audit_value(&request)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("count change request approved successfully");
+    Ok(Response::default())
+}
+
+pub fn try_reject(deps: DepsMut, env: Env, info: MessageInfo, request_id: String) -> StdResult<Response> {
+    let request = load_request_for_review(&deps, &info, &request_id)?;
+    CountChangeRequestManager::remove_request(deps.storage, &request_id, &request, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::RejectCountChangeRequest,
+        target: Some(request_id),
+        old_value: Some(to_audit_value(&request)?),
+        new_value: None,
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("count change request rejected successfully");
+    Ok(Response::default())
+}
+
+fn load_request_for_review(deps: &DepsMut, info: &MessageInfo, request_id: &String) -> StdResult<CountChangeRequest> {
+    if !RolesManager::has_capability(deps.storage, &info.sender, Capability::ResetCount)? {
+        return Err(StdError::generic_err("Only the owner or operators can review count change requests"));
+    }
+
+    CountChangeRequestManager::get_request(deps.storage, request_id, None).
+        ok_or_else(|| StdError::generic_err("Count change request not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{Addr};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::{COUNT_CHANGE_APPROVAL_THRESHOLD, Int64};
+    use crate::state::counters::{CounterTarget, DEFAULT_COUNTER_ID};
+    use crate::state::user_count_update_history::{UserCountUpdateHistoryManager};
+
+    #[test]
+    fn approve_n_reject_work() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        COUNT_CHANGE_APPROVAL_THRESHOLD.save(deps.as_mut().storage, &10)?;
+        let user_addr = Addr::unchecked("user1");
+
+        let _res = increment::try_increment(deps.as_mut(), mock_env(), mock_info("user1", &[]), Some(100), true, None, CounterTarget::Shared)?;
+        let _res = increment::try_increment(deps.as_mut(), mock_env(), mock_info("user1", &[]), Some(200), false, None, CounterTarget::Shared)?;
+        let requests = CountChangeRequestManager::get_user_requests(deps.as_ref().storage, user_addr.clone(), 0, 10, false, None)?;
+        assert_eq!(requests.len(), 2);
+
+        // Users cannot approve their own requests
+        assert!(try_approve(deps.as_mut(), mock_env(), mock_info("user1", &[]), requests[0].0.clone()).is_err());
+        let _res = try_approve(deps.as_mut(), mock_env(), mock_info("owner", &[]), requests[0].0.clone())?;
+        let _res = try_reject(deps.as_mut(), mock_env(), mock_info("owner", &[]), requests[1].0.clone())?;
+        // Already handled
+        assert!(try_approve(deps.as_mut(), mock_env(), mock_info("owner", &[]), requests[1].0.clone()).is_err());
+
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(100));
+        let history_entries = UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone(), 0, 10, false, None);
+        assert_eq!(history_entries.len(), 1);
+        assert_eq!(history_entries[0].count_change, Int64::new(100));
+        assert!(history_entries[0].marked_as_public_at.is_some());
+        assert_eq!(CountChangeRequestManager::get_user_requests_total_count(deps.as_ref().storage, user_addr, None)?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint64, to_binary};
+use crate::msg::ExecuteAnswer;
+use crate::state::{CONFIG, Int64, State};
+use crate::state::counters::{CounterInfo, CountersManager};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+const MAX_COUNTER_NAME_LENGTH: usize = 64;
+
+pub fn try_create_counter(deps: DepsMut, env: Env, info: MessageInfo, name: String, count: Int64, manager: Option<String>) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ResetCount)?;
+
+    if name.trim().is_empty() || name.chars().count() > MAX_COUNTER_NAME_LENGTH {
+        return Err(StdError::generic_err(format!("Counter name must be 1 to {} characters", MAX_COUNTER_NAME_LENGTH)));
+    }
+    CONFIG.load(deps.storage)?.counter_policy.ensure_count_within_bounds(count)?;
+    let manager_addr = manager.map(|m| deps.api.addr_validate(&m)).transpose()?;
+
+    let counter_info = CounterInfo {
+        name,
+        manager: manager_addr,
+        created_at: env.block.time,
+    };
+    let counter_id = CountersManager::add_counter(deps.storage, &env, counter_info.clone(), &State {
+        count,
+        count_increment_count: Uint64::zero(),
+        count_reset_count: Uint64::zero(),
+    })?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::CreateCounter,
+        target: Some(counter_id.clone()),
+        old_value: None,
+        new_value: Some(to_audit_value(&counter_info)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("counter created successfully");
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::CreateCounter { counter_id })?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_binary, Addr};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::counters::{CounterTarget, DEFAULT_COUNTER_ID};
+    use crate::execute::{increment, reset};
+
+    #[test]
+    fn create_counter_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        assert!(try_create_counter(deps.as_mut(), mock_env(), mock_info("creator", &[]), "Team".to_string(), Int64::new(10), None).is_err());
+        assert!(try_create_counter(deps.as_mut(), mock_env(), mock_info("owner", &[]), " ".to_string(), Int64::new(10), None).is_err());
+        assert!(try_create_counter(deps.as_mut(), mock_env(), mock_info("owner", &[]), "a".repeat(MAX_COUNTER_NAME_LENGTH + 1), Int64::new(10), None).is_err());
+        // Limit is in characters, not bytes
+        let _res = try_create_counter(deps.as_mut(), mock_env(), mock_info("owner", &[]), "計".repeat(MAX_COUNTER_NAME_LENGTH), Int64::new(10), None)?;
+        let res = try_create_counter(deps.as_mut(), mock_env(), mock_info("owner", &[]), "Team".to_string(), Int64::new(10), Some("manager".to_string()))?;
+        let ExecuteAnswer::CreateCounter { counter_id } = from_binary(&res.data.unwrap())? else {
+            panic!("unexpected answer");
+        };
+
+        let _res = increment::try_increment(deps.as_mut(), mock_env(), mock_info("user1", &[]), Some(2), false, Some(counter_id.clone()), CounterTarget::Shared)?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &counter_id)?.count, Int64::new(12));
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(0));
+        assert!(increment::try_increment(deps.as_mut(), mock_env(), mock_info("user1", &[]), None, false, Some("missing".to_string()), CounterTarget::Shared).is_err());
+
+        // Manager can only reset own counter
+        assert!(reset::try_reset(deps.as_mut(), mock_env(), mock_info("manager", &[]), Int64::new(0), None).is_err());
+        let _res = reset::try_reset(deps.as_mut(), mock_env(), mock_info("manager", &[]), Int64::new(0), Some(counter_id.clone()))?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &counter_id)?.count, Int64::new(0));
+        assert_eq!(CountersManager::get_info(deps.as_ref().storage, &counter_id).unwrap().manager, Some(Addr::unchecked("manager")));
+
+        Ok(())
+    }
+}
@@ -1,28 +1,79 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
-use crate::state::{STATE};
-use crate::state::user_statistic_data::{ USER_STATISTIC_DATA_STORE};
-use crate::state::user_count_update_history::{UserCountUpdateHistoryManager, UserCountUpdateHistoryEntry};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdError, Storage, Uint64};
+use crate::error::ContractError;
+use crate::state::{CONFIG, Int64, State};
+use crate::state::counters::{CountersManager, CounterTarget};
+use crate::state::rate_limit::{RateLimitManager};
+use crate::state::count_change_request::{CountChangeRequest, CountChangeRequestManager};
+use crate::state::user_statistic_data::{get_counter_specific_store};
+use crate::state::user_count_update_history::{UserCountUpdateHistoryManager, UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
 
-pub fn try_increment(deps: DepsMut, env: Env, info: MessageInfo, count: Option<i32>, mark_history_as_public: bool) -> StdResult<Response> {
-    let mut state = STATE.load(deps.storage)?;
+pub fn try_increment(deps: DepsMut, env: Env, info: MessageInfo, count: Option<i32>, mark_history_as_public: bool, counter_id: Option<String>, target: CounterTarget) -> Result<Response, ContractError> {
     let count_change = if count.is_some() {
         count.unwrap()
     }
     else {
         1
     };
-    state.count += count_change;
-    state.count_increment_count += 1;
+    let counter_policy = CONFIG.load(deps.storage)?.counter_policy;
+    counter_policy.ensure_step_allowed(count_change)?;
+    let counter_id = match target {
+        CounterTarget::Shared => {
+            let counter_id = CountersManager::resolve_counter_id(counter_id);
+            // Only owners can increment their personal counters
+            if CountersManager::is_personal_counter_id(&counter_id) {
+                return Err(StdError::generic_err("Counter not found").into());
+            }
+            counter_id
+        },
+        CounterTarget::Personal => {
+            if counter_id.is_some() {
+                return Err(StdError::generic_err("counter_id cannot be used with personal target").into());
+            }
+            CountersManager::ensure_personal_counter(deps.storage, &env, &info.sender)?
+        },
+    };
+    load_state_n_new_count(deps.storage, &counter_id, count_change)?;
+    RateLimitManager::check_n_record_increment(deps.storage, &env, &info.sender)?;
+
+    // Personal counters affect no one else
+    if target == CounterTarget::Shared && CountChangeRequestManager::requires_approval(deps.storage, count_change)? {
+        CountChangeRequestManager::add_request(deps.storage, &env, CountChangeRequest {
+            user_addr: info.sender,
+            count_change,
+            mark_history_as_public,
+            counter_id: Some(counter_id),
+            created_at: env.block.time,
+        }, None)?;
 
-    STATE.save(deps.storage, &state)?;
+        deps.api.debug("count change request created successfully");
+        return Ok(Response::default());
+    }
 
-    let mut user_stats = USER_STATISTIC_DATA_STORE.get(deps.storage, &info.sender).unwrap_or_default();
-    user_stats.count_increment_count += 1;
-    USER_STATISTIC_DATA_STORE.insert(deps.storage, &info.sender, &user_stats)?;
+    apply_increment(deps.storage, &env, &counter_id, info.sender, count_change, mark_history_as_public)?;
 
-    UserCountUpdateHistoryManager::add_entry(deps.storage, &env, UserCountUpdateHistoryEntry{
-        user_addr: info.sender.clone(),
-        count_change,
+    deps.api.debug("count incremented successfully");
+    Ok(Response::default())
+}
+
+/// Also used by approved count change requests, bounds are checked again since count might have changed
+pub fn apply_increment(storage: &mut dyn Storage, env: &Env, counter_id: &str, user_addr: Addr, count_change: i32, mark_history_as_public: bool) -> Result<(), ContractError> {
+    let (mut state, new_count) = load_state_n_new_count(storage, counter_id, count_change)?;
+
+    state.count = new_count;
+    state.count_increment_count = state.count_increment_count.checked_add(Uint64::one()).map_err(|_| ContractError::CountOverflow)?;
+
+    CountersManager::save_state(storage, counter_id, &state)?;
+
+    let user_statistic_data_store = get_counter_specific_store(counter_id);
+    let mut user_stats = user_statistic_data_store.get(storage, &user_addr).unwrap_or_default();
+    user_stats.count_increment_count = user_stats.count_increment_count.checked_add(Uint64::one()).map_err(|_| ContractError::CountOverflow)?;
+    user_statistic_data_store.insert(storage, &user_addr, &user_stats)?;
+
+    UserCountUpdateHistoryManager::add_entry(storage, env, counter_id, UserCountUpdateHistoryEntry{
+        user_addr,
+        count_change: Int64::from(count_change),
+        resulting_count: Some(new_count),
+        kind: UserCountUpdateHistoryEntryKind::Increment,
         created_at: env.block.time.clone(),
         marked_as_public_at: if mark_history_as_public {
             Some(env.block.time.clone())
@@ -32,18 +83,26 @@ pub fn try_increment(deps: DepsMut, env: Env, info: MessageInfo, count: Option<i
         },
     }, None)?;
 
-    deps.api.debug("count incremented successfully");
-    Ok(Response::default())
+    Ok(())
+}
+
+fn load_state_n_new_count(storage: &dyn Storage, counter_id: &str, count_change: i32) -> Result<(State, Int64), ContractError> {
+    let state = CountersManager::get_state(storage, counter_id)?;
+    let new_count = state.count.checked_add(Int64::from(count_change)).ok_or(ContractError::CountOverflow)?;
+    CONFIG.load(storage)?.counter_policy.ensure_count_within_bounds(new_count)?;
+
+    Ok((state, new_count))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::{Addr, Coin, Uint128};
+    use cosmwasm_std::{Addr, Coin, StdResult, Uint128};
     use cosmwasm_std::testing::{mock_dependencies_with_balance, mock_env, mock_info};
     use crate::msg::{InstantiateMsg};
-    use crate::state::{State, STATE};
+    use crate::state::{CounterPolicy, COUNT_CHANGE_APPROVAL_THRESHOLD, RATE_LIMIT_POLICY, RateLimitPolicy};
     use crate::state::user_statistic_data::{UserStatisticData};
+    use crate::state::counters::{DEFAULT_COUNTER_ID};
 
     #[test]
     fn increment_without_anything_works() -> StdResult<()> {
@@ -62,24 +121,24 @@ mod tests {
         let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
 
         let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
-        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), None, false)?;
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), None, false, None, CounterTarget::Shared)?;
 
         // should increase counter by 1
-        let state = STATE.load(deps.as_ref().storage);
+        let state = CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID);
         assert_eq!(
             state,
             Ok(State {
-                count: 18,
-                count_increment_count: 1,
-                count_reset_count: 0,
+                count: Int64::new(18),
+                count_increment_count: Uint64::new(1),
+                count_reset_count: Uint64::new(0),
             })
         );
 
         assert_eq!(
-            USER_STATISTIC_DATA_STORE.get(deps.as_mut().storage, &Addr::unchecked(creator)),
+            get_counter_specific_store(DEFAULT_COUNTER_ID).get(deps.as_mut().storage, &Addr::unchecked(creator)),
             Some(
                 UserStatisticData {
-                    count_increment_count: 1,
+                    count_increment_count: Uint64::new(1),
                 }
             )
         );
@@ -104,24 +163,24 @@ mod tests {
         let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
 
         let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
-        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(3), false)?;
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(3), false, None, CounterTarget::Shared)?;
 
         // should increase counter by N
-        let state = STATE.load(deps.as_ref().storage);
+        let state = CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID);
         assert_eq!(
             state,
             Ok(State {
-                count: 20,
-                count_increment_count: 1,
-                count_reset_count: 0,
+                count: Int64::new(20),
+                count_increment_count: Uint64::new(1),
+                count_reset_count: Uint64::new(0),
             })
         );
 
         assert_eq!(
-            USER_STATISTIC_DATA_STORE.get(deps.as_mut().storage, &Addr::unchecked(creator)),
+            get_counter_specific_store(DEFAULT_COUNTER_ID).get(deps.as_mut().storage, &Addr::unchecked(creator)),
             Some(
                 UserStatisticData {
-                    count_increment_count: 1,
+                    count_increment_count: Uint64::new(1),
                 }
             )
         );
@@ -146,15 +205,116 @@ mod tests {
         let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
 
         let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
-        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(3), true)?;
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(3), true, None, CounterTarget::Shared)?;
 
         // should add public entry
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_public_entries_total_count(deps.as_ref().storage, None)?,
+            UserCountUpdateHistoryManager::get_public_entries_total_count(deps.as_ref().storage, DEFAULT_COUNTER_ID, None)?,
             1,
         );
 
         Ok(())
     }
+
+    #[test]
+    fn increment_with_counter_policy_works() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let info = mock_info("creator", &[]);
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
+
+        let mut config = CONFIG.load(deps.as_ref().storage)?;
+        config.counter_policy = CounterPolicy {
+            min_step: None,
+            max_step: Some(Int64::new(5)),
+            count_floor: None,
+            count_ceiling: Some(Int64::new(25)),
+            allow_negative_steps: false,
+            max_memo_length: None,
+        };
+        CONFIG.save(deps.as_mut().storage, &config)?;
+
+        assert!(try_increment(deps.as_mut(), mock_env(), info.clone(), Some(-1), false, None, CounterTarget::Shared).is_err());
+        assert!(try_increment(deps.as_mut(), mock_env(), info.clone(), Some(6), false, None, CounterTarget::Shared).is_err());
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(5), false, None, CounterTarget::Shared)?;
+        // Would go over ceiling
+        assert!(try_increment(deps.as_mut(), mock_env(), info.clone(), Some(5), false, None, CounterTarget::Shared).is_err());
+
+        // Rejected ones are not counted
+        assert_eq!(
+            CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?,
+            State {
+                count: Int64::new(22),
+                count_increment_count: Uint64::new(1),
+                count_reset_count: Uint64::new(0),
+            },
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn increment_over_approval_threshold_works() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let info = mock_info("creator", &[]);
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
+        COUNT_CHANGE_APPROVAL_THRESHOLD.save(deps.as_mut().storage, &10)?;
+
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(10), false, None, CounterTarget::Shared)?;
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(-11), false, None, CounterTarget::Shared)?;
+
+        // Only the one within threshold is applied
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(27));
+        assert_eq!(UserCountUpdateHistoryManager::get_user_entries_total_count(deps.as_ref().storage, DEFAULT_COUNTER_ID, Addr::unchecked("creator"))?, 1);
+        assert_eq!(CountChangeRequestManager::get_user_requests_total_count(deps.as_ref().storage, Addr::unchecked("creator"), None)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn increment_personal_counter_works() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let info = mock_info("creator", &[]);
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
+
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(3), false, None, CounterTarget::Personal)?;
+        let _res = try_increment(deps.as_mut(), mock_env(), info.clone(), Some(2), false, None, CounterTarget::Personal)?;
+
+        let personal_counter_id = CountersManager::personal_counter_id(&Addr::unchecked("creator"));
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &personal_counter_id)?.count, Int64::new(5));
+        // Shared counter untouched
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+        assert_eq!(UserCountUpdateHistoryManager::get_user_entries_total_count(deps.as_ref().storage, &personal_counter_id, Addr::unchecked("creator"))?, 2);
+
+        // Others cannot increment it
+        assert!(try_increment(deps.as_mut(), mock_env(), mock_info("other", &[]), None, false, Some(personal_counter_id.clone()), CounterTarget::Shared).is_err());
+        assert!(try_increment(deps.as_mut(), mock_env(), info.clone(), None, false, Some(DEFAULT_COUNTER_ID.to_string()), CounterTarget::Personal).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rate_limited_increment_keeps_typed_error() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let env = mock_env();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), init_msg)?;
+        RATE_LIMIT_POLICY.save(deps.as_mut().storage, &RateLimitPolicy {
+            increment_cooldown_in_seconds: Some(10),
+            max_bookmarks_created_per_day: None,
+            max_count_changes_per_block: None,
+        })?;
+
+        let increment_msg = crate::msg::ExecuteMsg::Increment { count: None, mark_history_as_public: None, counter_id: None, target: None };
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), env.clone(), mock_info("user", &[]), increment_msg.clone())?;
+        assert_eq!(
+            crate::execute(deps.as_mut(), env.clone(), mock_info("user", &[]), increment_msg),
+            Err(ContractError::RateLimited { retry_after: env.block.time.plus_seconds(10) }),
+        );
+
+        Ok(())
+    }
 }
@@ -1,34 +1,137 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use secret_toolkit::utils::{pad_handle_result};
+use crate::error::ContractError;
 use crate::msg::ExecuteMsg;
 use crate::state::BLOCK_SIZE;
+use crate::state::counters::CounterTarget;
 
 mod increment;
+mod counters;
+mod count_change_request;
 mod reset;
+mod adjust_count;
+mod config;
+mod contract_manager;
+mod roles;
+mod multisig;
+mod timelock;
+mod privileged_action;
+mod pause;
+mod access_control;
 mod permits;
+mod viewing_keys;
+mod read_grants;
+mod contract_authorizations;
 mod bookmarked_numbers;
+mod announcements;
 
 pub fn execute_dispatch(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
+    pause::ensure_msg_not_paused(deps.storage, &msg)?;
+    access_control::ensure_msg_allowed(deps.storage, &info.sender, &msg)?;
+    multisig::ensure_msg_not_requiring_proposal(deps.storage, &info.sender, &msg)?;
+
+    // Handlers returning `ContractError` return early, so typed errors like `ContractError::RateLimited` are kept
     let res = match msg {
-        ExecuteMsg::Increment { count, mark_history_as_public } => {
-            increment::try_increment(deps, env, info, count, mark_history_as_public.unwrap_or(false))
+        ExecuteMsg::Increment { count, mark_history_as_public, counter_id, target } => {
+            return pad_handle_result(increment::try_increment(deps, env, info, count, mark_history_as_public.unwrap_or(false), counter_id, target.unwrap_or(CounterTarget::Shared)), BLOCK_SIZE);
+        },
+        ExecuteMsg::Reset { count, counter_id } => return pad_handle_result(reset::try_reset(deps, env, info, count, counter_id), BLOCK_SIZE),
+        ExecuteMsg::AdjustCount { count_change, counter_id } => return pad_handle_result(adjust_count::try_adjust_count(deps, env, info, count_change, counter_id), BLOCK_SIZE),
+        ExecuteMsg::CreateCounter { name, count, manager } => counters::try_create_counter(deps, env, info, name, count, manager),
+        ExecuteMsg::ApproveCountChangeRequest { request_id } => return pad_handle_result(count_change_request::try_approve(deps, env, info, request_id), BLOCK_SIZE),
+        ExecuteMsg::RejectCountChangeRequest { request_id } => count_change_request::try_reject(deps, env, info, request_id),
+
+        ExecuteMsg::UpdateConfig { counter_policy } => config::try_update_config(deps, env, info, counter_policy),
+        ExecuteMsg::UpdateRateLimitPolicy { rate_limit_policy } => config::try_update_rate_limit_policy(deps, env, info, rate_limit_policy),
+        ExecuteMsg::UpdatePermitPolicy { permit_policy } => config::try_update_permit_policy(deps, env, info, permit_policy),
+        ExecuteMsg::SetCountChangeApprovalThreshold { threshold } => config::try_set_count_change_approval_threshold(deps, env, info, threshold),
+
+        ExecuteMsg::ProposeContractManager { address } => contract_manager::try_propose_contract_manager(deps, env, info, address),
+        ExecuteMsg::AcceptContractManager {} => contract_manager::try_accept_contract_manager(deps, env, info),
+        ExecuteMsg::CancelManagerProposal {} => contract_manager::try_cancel_manager_proposal(deps, env, info),
+
+        ExecuteMsg::GrantRole { address, role } => roles::try_grant_role(deps, env, info, address, role),
+        ExecuteMsg::RevokeRole { address, role } => roles::try_revoke_role(deps, env, info, address, role),
+
+        ExecuteMsg::SetApproverSet { approvers, threshold, proposal_ttl_in_seconds } => {
+            multisig::try_set_approver_set(deps, env, info, approvers, threshold, proposal_ttl_in_seconds)
         },
-        ExecuteMsg::Reset { count } => reset::try_reset(deps, info, count),
+        ExecuteMsg::ProposePrivilegedAction { action } => return pad_handle_result(multisig::try_propose_privileged_action(deps, env, info, action), BLOCK_SIZE),
+        ExecuteMsg::ApproveProposal { proposal_id } => return pad_handle_result(multisig::try_approve_proposal(deps, env, info, proposal_id), BLOCK_SIZE),
+
+        ExecuteMsg::SetTimelockDelay { delay_in_seconds } => timelock::try_set_timelock_delay(deps, env, info, delay_in_seconds),
+        ExecuteMsg::CancelQueuedAction { queued_action_id } => timelock::try_cancel_queued_action(deps, env, info, queued_action_id),
+        ExecuteMsg::ExecuteQueuedAction { queued_action_id } => return pad_handle_result(timelock::try_execute_queued_action(deps, env, info, queued_action_id), BLOCK_SIZE),
+
+        ExecuteMsg::SetPauseState { increment, reset, bookmarks, public_queries } => {
+            pause::try_set_pause_state(deps, env, info, pause::SetPauseStatePayload {
+                increment,
+                reset,
+                bookmarks,
+                public_queries,
+            })
+        },
+
+        ExecuteMsg::SetAccessMode { mode } => access_control::try_set_access_mode(deps, env, info, mode),
+        ExecuteMsg::AddToAccessList { list, addresses } => access_control::try_add_to_access_list(deps, env, info, list, addresses),
+        ExecuteMsg::RemoveFromAccessList { list, addresses } => access_control::try_remove_from_access_list(deps, env, info, list, addresses),
+        ExecuteMsg::CreateInviteCode { code, max_uses } => access_control::try_create_invite_code(deps, env, info, code, max_uses),
+        ExecuteMsg::RedeemInviteCode { code } => access_control::try_redeem_invite_code(deps, env, info, code),
 
         ExecuteMsg::AddBookmarkNumber { number, memo_text, mark_entry_as_public } => {
-            bookmarked_numbers::add_bookmark_number::execute(deps, env, info, number, memo_text, mark_entry_as_public, None)
+            return pad_handle_result(bookmarked_numbers::add_bookmark_number::execute(deps, env, info, number, memo_text, mark_entry_as_public, None), BLOCK_SIZE);
         },
         ExecuteMsg::UpdateBookmarkedNumber { entry_id, memo_text, mark_entry_as_public } => {
             bookmarked_numbers::update_bookmark_number_entry::execute(deps, env, info, entry_id, memo_text, mark_entry_as_public, None)
         },
+        ExecuteMsg::HideBookmarkedNumberEntry { entry_id, reason } => {
+            bookmarked_numbers::moderate_bookmark_number_entry::hide(deps, env, info, entry_id, reason, None)
+        },
+        ExecuteMsg::RestoreBookmarkedNumberEntry { entry_id } => {
+            bookmarked_numbers::moderate_bookmark_number_entry::restore(deps, env, info, entry_id, None)
+        },
+
+        ExecuteMsg::PostAnnouncement { title, body, severity, visible_from_in_ms, visible_until_in_ms } => {
+            announcements::try_post(deps, env, info, announcements::AnnouncementPayload {
+                title,
+                body,
+                severity,
+                visible_from_in_ms,
+                visible_until_in_ms,
+            })
+        },
+        ExecuteMsg::EditAnnouncement { announcement_id, title, body, severity, visible_from_in_ms, visible_until_in_ms } => {
+            announcements::try_edit(deps, env, info, announcement_id, announcements::AnnouncementPayload {
+                title,
+                body,
+                severity,
+                visible_from_in_ms,
+                visible_until_in_ms,
+            })
+        },
+        ExecuteMsg::ExpireAnnouncement { announcement_id } => announcements::try_expire(deps, env, info, announcement_id),
+        ExecuteMsg::DeleteAnnouncement { announcement_id } => announcements::try_delete(deps, env, info, announcement_id),
+
+        ExecuteMsg::CreateViewingKey { entropy, .. } => viewing_keys::try_create_viewing_key(deps, env, info, entropy),
+        ExecuteMsg::SetViewingKey { key, .. } => viewing_keys::try_set_viewing_key(deps, env, info, key),
+        ExecuteMsg::GrantReadAccess { grantee, scopes, expires_at_in_ms } => read_grants::try_grant_read_access(deps, env, info, grantee, scopes, expires_at_in_ms),
+        ExecuteMsg::RevokeReadAccess { grantee } => read_grants::try_revoke_read_access(deps, env, info, grantee),
+
+        ExecuteMsg::AuthorizeContract { contract_address, code_hash, scopes, expires_at_in_ms } => {
+            contract_authorizations::try_authorize_contract(deps, env, info, contract_address, code_hash, scopes, expires_at_in_ms)
+        },
+        ExecuteMsg::RevokeContractAuthorization { contract_address } => {
+            contract_authorizations::try_revoke_contract_authorization(deps, env, info, contract_address)
+        },
 
         ExecuteMsg::RevokePermit { permit_name, .. } => permits::revoke_permit(deps, env, info, permit_name),
+        ExecuteMsg::RevokeAllPermits { .. } => permits::revoke_all_permits(deps, env, info),
     };
 
-    pad_handle_result(res, BLOCK_SIZE)
+    pad_handle_result(res, BLOCK_SIZE).map_err(ContractError::from)
 }
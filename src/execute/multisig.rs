@@ -0,0 +1,238 @@
+use cosmwasm_std::{Addr, Api, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::CONFIG;
+use crate::state::counters::{CountersManager};
+use crate::state::multisig::{ApproverSet, MultisigManager, PrivilegedAction, Proposal, DEFAULT_PROPOSAL_TTL_IN_SECONDS};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use super::privileged_action;
+
+pub fn try_set_approver_set(deps: DepsMut, env: Env, info: MessageInfo, approvers: Vec<String>, threshold: u32, proposal_ttl_in_seconds: Option<u64>) -> StdResult<Response> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.contract_manager {
+        return Err(StdError::generic_err("Only the contract manager can set approvers"));
+    }
+
+    perform_set_approver_set(deps, env, info.sender, approvers, threshold, proposal_ttl_in_seconds)
+}
+
+pub fn try_propose_privileged_action(deps: DepsMut, env: Env, info: MessageInfo, action: PrivilegedAction) -> Result<Response, ContractError> {
+    let approver_set = ensure_enabled(deps.storage)?;
+    approver_set.ensure_approver(&info.sender)?;
+    // Fail early instead of when threshold is met
+    privileged_action::validate(deps.api, &action)?;
+
+    let proposal = Proposal {
+        proposer_addr: info.sender.clone(),
+        action,
+        // Proposing counts as approving
+        approvals: vec![info.sender.clone()],
+        created_at: env.block.time,
+        expires_at: env.block.time.plus_seconds(approver_set.proposal_ttl_in_seconds),
+    };
+    let proposal_id = MultisigManager::add_proposal(deps.storage, &env, proposal.clone(), None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender.clone(),
+        action: AuditAction::ProposePrivilegedAction,
+        target: Some(proposal_id.clone()),
+        old_value: None,
+        new_value: Some(to_audit_value(&proposal.action)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    execute_if_threshold_met(deps, env, info.sender, &approver_set, proposal_id, proposal)
+}
+
+pub fn try_approve_proposal(deps: DepsMut, env: Env, info: MessageInfo, proposal_id: String) -> Result<Response, ContractError> {
+    let approver_set = ensure_enabled(deps.storage)?;
+    approver_set.ensure_approver(&info.sender)?;
+
+    let mut proposal = MultisigManager::get_proposal(deps.storage, &proposal_id, None).
+        ok_or_else(|| StdError::generic_err("Proposal not found"))?;
+    if proposal.is_expired(&env) {
+        return Err(StdError::generic_err("Proposal expired").into());
+    }
+    if proposal.approvals.contains(&info.sender) {
+        return Err(StdError::generic_err("Proposal already approved").into());
+    }
+    proposal.approvals.push(info.sender.clone());
+    MultisigManager::update_proposal(deps.storage, &proposal_id, &proposal, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender.clone(),
+        action: AuditAction::ApproveProposal,
+        target: Some(proposal_id.clone()),
+        old_value: None,
+        new_value: None,
+        created_at: env.block.time,
+    }, None)?;
+
+    execute_if_threshold_met(deps, env, info.sender, &approver_set, proposal_id, proposal)
+}
+
+/// Checked by `execute_dispatch` before any handler runs
+pub fn ensure_msg_not_requiring_proposal(storage: &dyn Storage, sender: &Addr, msg: &ExecuteMsg) -> StdResult<()> {
+    match msg {
+        // Owners of personal counters act on their own data only, shared counters still need proposals
+        ExecuteMsg::Reset { counter_id, .. } |
+        ExecuteMsg::AdjustCount { counter_id, .. } if is_own_personal_counter(storage, &CountersManager::resolve_counter_id(counter_id.clone()), sender) => Ok(()),
+        ExecuteMsg::Reset { .. } |
+        ExecuteMsg::AdjustCount { .. } |
+        ExecuteMsg::UpdateConfig { .. } |
+        ExecuteMsg::UpdateRateLimitPolicy { .. } |
+        ExecuteMsg::UpdatePermitPolicy { .. } |
+        ExecuteMsg::SetCountChangeApprovalThreshold { .. } |
+        ExecuteMsg::GrantRole { .. } |
+        ExecuteMsg::RevokeRole { .. } |
+        ExecuteMsg::SetApproverSet { .. } |
+        ExecuteMsg::SetTimelockDelay { .. } |
+        // Approvers agreed on queued actions, one key should not undo that
+        ExecuteMsg::CancelQueuedAction { .. } => {
+            if MultisigManager::get_approver_set(storage)?.is_some() {
+                return Err(StdError::generic_err("Multisig enabled, submit a proposal instead"));
+            }
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+fn is_own_personal_counter(storage: &dyn Storage, counter_id: &str, sender: &Addr) -> bool {
+    CountersManager::is_personal_counter_id(counter_id) && CountersManager::is_manager(storage, counter_id, sender)
+}
+
+fn ensure_enabled(storage: &dyn Storage) -> StdResult<ApproverSet> {
+    MultisigManager::get_approver_set(storage)?.ok_or_else(|| StdError::generic_err("Multisig not enabled"))
+}
+
+fn execute_if_threshold_met(deps: DepsMut, env: Env, actor_addr: Addr, approver_set: &ApproverSet, proposal_id: String, proposal: Proposal) -> Result<Response, ContractError> {
+    if !approver_set.is_threshold_met(&proposal) {
+        deps.api.debug("proposal approved, waiting for more approvals");
+        return Ok(Response::default());
+    }
+
+    MultisigManager::remove_proposal(deps.storage, &proposal_id, None)?;
+    privileged_action::perform_or_queue(deps, env, actor_addr, proposal.action)
+}
+
+/// Empty approvers disables multisig
+pub fn build_approver_set(api: &dyn Api, approvers: &[String], threshold: u32, proposal_ttl_in_seconds: Option<u64>) -> StdResult<Option<ApproverSet>> {
+    if approvers.is_empty() {
+        return Ok(None);
+    }
+
+    let mut approver_addrs: Vec<Addr> = vec![];
+    for approver in approvers {
+        let addr = api.addr_validate(approver)?;
+        if !approver_addrs.contains(&addr) {
+            approver_addrs.push(addr);
+        }
+    }
+    if threshold < 1 || threshold as usize > approver_addrs.len() {
+        return Err(StdError::generic_err("Threshold must be between 1 and the number of approvers"));
+    }
+
+    Ok(Some(ApproverSet {
+        approvers: approver_addrs,
+        threshold,
+        proposal_ttl_in_seconds: proposal_ttl_in_seconds.unwrap_or(DEFAULT_PROPOSAL_TTL_IN_SECONDS),
+    }))
+}
+
+/// Also used by approved multisig proposals, caller must check authorization
+pub fn perform_set_approver_set(deps: DepsMut, env: Env, actor_addr: Addr, approvers: Vec<String>, threshold: u32, proposal_ttl_in_seconds: Option<u64>) -> StdResult<Response> {
+    let approver_set = build_approver_set(deps.api, &approvers, threshold, proposal_ttl_in_seconds)?;
+    let old_approver_set = MultisigManager::get_approver_set(deps.storage)?;
+    MultisigManager::set_approver_set(deps.storage, approver_set.clone())?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::SetApproverSet,
+        target: None,
+        old_value: Some(to_audit_value(&old_approver_set)?),
+        new_value: Some(to_audit_value(&approver_set)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("approver set updated successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::counters::{CountersManager, DEFAULT_COUNTER_ID};
+    use crate::state::{Int64, PAUSE_STATE, PauseState};
+
+    #[test]
+    fn multisig_reset_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let reset_msg = ExecuteMsg::Reset { count: Int64::new(0), counter_id: None };
+
+        // Not enabled yet
+        assert!(ensure_msg_not_requiring_proposal(deps.as_ref().storage, &Addr::unchecked("owner"), &reset_msg).is_ok());
+        assert!(try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::Reset { count: Int64::new(0), counter_id: None }).is_err());
+
+        assert!(try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string()], 2, None).is_err());
+        let _res = try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, None)?;
+        assert!(ensure_msg_not_requiring_proposal(deps.as_ref().storage, &Addr::unchecked("owner"), &reset_msg).is_err());
+
+        // Only approvers can propose
+        assert!(try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("owner", &[]), PrivilegedAction::Reset { count: Int64::new(0), counter_id: None }).is_err());
+        let _res = try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::Reset { count: Int64::new(0), counter_id: None })?;
+        let (proposal_id, _proposal) = MultisigManager::get_proposals(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+
+        assert!(try_approve_proposal(deps.as_mut(), mock_env(), mock_info("approver1", &[]), proposal_id.clone()).is_err());
+        let _res = try_approve_proposal(deps.as_mut(), mock_env(), mock_info("approver2", &[]), proposal_id.clone())?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(0));
+        assert_eq!(MultisigManager::get_proposals_total_count(deps.as_ref().storage, None)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn approved_proposal_respects_pause() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let _res = try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, None)?;
+
+        let _res = try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::AdjustCount { count_change: 3, counter_id: None })?;
+        let (proposal_id, _proposal) = MultisigManager::get_proposals(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+        PAUSE_STATE.save(deps.as_mut().storage, &PauseState { reset_paused: true, ..Default::default() })?;
+
+        assert!(try_approve_proposal(deps.as_mut(), mock_env(), mock_info("approver2", &[]), proposal_id.clone()).is_err());
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+
+        // Can be approved after unpausing
+        PAUSE_STATE.save(deps.as_mut().storage, &PauseState::default())?;
+        let _res = try_approve_proposal(deps.as_mut(), mock_env(), mock_info("approver2", &[]), proposal_id)?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(20));
+
+        Ok(())
+    }
+
+    #[test]
+    fn expired_proposal_cannot_be_approved() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let _res = try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, Some(60))?;
+
+        let _res = try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::Reset { count: Int64::new(0), counter_id: None })?;
+        let (proposal_id, _proposal) = MultisigManager::get_proposals(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(60);
+        assert!(try_approve_proposal(deps.as_mut(), env, mock_info("approver2", &[]), proposal_id).is_err());
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use crate::msg::ExecuteMsg;
+use crate::state::{PAUSE_STATE, PauseState, PausableOperation};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub struct SetPauseStatePayload {
+    pub increment: Option<bool>,
+    pub reset: Option<bool>,
+    pub bookmarks: Option<bool>,
+    pub public_queries: Option<bool>,
+}
+
+pub fn try_set_pause_state(deps: DepsMut, env: Env, info: MessageInfo, payload: SetPauseStatePayload) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManagePause)?;
+
+    let old_pause_state = PAUSE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    let mut pause_state = old_pause_state.clone();
+    // Only update the switches provided
+    if let Some(paused) = payload.increment { pause_state.increment_paused = paused; }
+    if let Some(paused) = payload.reset { pause_state.reset_paused = paused; }
+    if let Some(paused) = payload.bookmarks { pause_state.bookmarks_paused = paused; }
+    if let Some(paused) = payload.public_queries { pause_state.public_queries_paused = paused; }
+    PAUSE_STATE.save(deps.storage, &pause_state)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: info.sender,
+        action: AuditAction::SetPauseState,
+        target: None,
+        old_value: Some(to_audit_value(&old_pause_state)?),
+        new_value: Some(to_audit_value(&pause_state)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("pause state updated successfully");
+    Ok(Response::default())
+}
+
+/// Checked by `execute_dispatch` before any handler runs
+pub fn ensure_msg_not_paused(storage: &dyn Storage, msg: &ExecuteMsg) -> StdResult<()> {
+    let operation = match msg {
+        ExecuteMsg::Increment { .. } |
+        ExecuteMsg::ApproveCountChangeRequest { .. } => PausableOperation::Increment,
+        ExecuteMsg::Reset { .. } |
+        ExecuteMsg::AdjustCount { .. } => PausableOperation::Reset,
+        ExecuteMsg::AddBookmarkNumber { .. } |
+        ExecuteMsg::UpdateBookmarkedNumber { .. } => PausableOperation::Bookmarks,
+        _ => return Ok(()),
+    };
+
+    PauseState::ensure_not_paused(storage, operation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::Int64;
+
+    #[test]
+    fn set_pause_state_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: None, mark_history_as_public: None, counter_id: None, target: None };
+        let reset_msg = ExecuteMsg::Reset { count: Int64::new(0), counter_id: None };
+
+        assert!(try_set_pause_state(deps.as_mut(), mock_env(), mock_info("creator", &[]), SetPauseStatePayload {
+            increment: Some(true),
+            reset: None,
+            bookmarks: None,
+            public_queries: None,
+        }).is_err());
+
+        let _res = try_set_pause_state(deps.as_mut(), mock_env(), mock_info("owner", &[]), SetPauseStatePayload {
+            increment: Some(true),
+            reset: None,
+            bookmarks: None,
+            public_queries: None,
+        })?;
+        assert!(ensure_msg_not_paused(deps.as_ref().storage, &increment_msg).is_err());
+        assert!(ensure_msg_not_paused(deps.as_ref().storage, &reset_msg).is_ok());
+
+        let _res = try_set_pause_state(deps.as_mut(), mock_env(), mock_info("owner", &[]), SetPauseStatePayload {
+            increment: Some(false),
+            reset: Some(true),
+            bookmarks: None,
+            public_queries: None,
+        })?;
+        assert!(ensure_msg_not_paused(deps.as_ref().storage, &increment_msg).is_ok());
+        assert!(ensure_msg_not_paused(deps.as_ref().storage, &reset_msg).is_err());
+        assert_eq!(PAUSE_STATE.load(deps.as_ref().storage)?, PauseState {
+            increment_paused: false,
+            reset_paused: true,
+            bookmarks_paused: false,
+            public_queries_paused: false,
+        });
+
+        Ok(())
+    }
+}
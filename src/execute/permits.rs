@@ -2,10 +2,11 @@ use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
 use secret_toolkit::permit::{RevokedPermits};
 
 use crate::state::PREFIX_REVOKED_PERMITS;
+use crate::state::permits::{PermitsManager};
 
 pub fn revoke_permit(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     permit_name: String,
 ) -> StdResult<Response> {
@@ -15,6 +16,40 @@ pub fn revoke_permit(
         info.sender.as_ref(),
         &permit_name,
     );
+    PermitsManager::add_revoked_permit(deps.storage, &env, &info.sender, &permit_name)?;
 
     Ok(Response::new())
 }
+
+/// Rejects every permit signed so far, including those not revoked one by one
+/// Does not help when the signing key leaked, since new permits can still be signed with it
+pub fn revoke_all_permits(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> StdResult<Response> {
+    PermitsManager::bump_epoch(deps.storage, &env, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn revoke_permits_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let info = mock_info("user1", &[]);
+
+        let _res = revoke_permit(deps.as_mut(), mock_env(), info.clone(), "permit1".to_string())?;
+        assert!(RevokedPermits::is_permit_revoked(deps.as_ref().storage, PREFIX_REVOKED_PERMITS, "user1", "permit1"));
+        assert_eq!(PermitsManager::get_revoked_permits_total_count(deps.as_ref().storage, &info.sender)?, 1);
+
+        let _res = revoke_all_permits(deps.as_mut(), mock_env(), info.clone())?;
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &info.sender, None).is_err());
+
+        Ok(())
+    }
+}
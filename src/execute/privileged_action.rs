@@ -0,0 +1,86 @@
+use cosmwasm_std::{Addr, Api, DepsMut, Env, Response, StdError, StdResult};
+use crate::error::ContractError;
+use crate::state::{PauseState, PausableOperation};
+use crate::state::counters::{CountersManager};
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::timelock::{QueuedAction, TimelockManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use super::{adjust_count, config, multisig, reset, roles, timelock};
+
+/// Caller must check authorization
+pub fn perform(deps: DepsMut, env: Env, actor_addr: Addr, action: PrivilegedAction) -> Result<Response, ContractError> {
+    // Approved proposals and queued actions bypass `pause::ensure_msg_not_paused`
+    if let PrivilegedAction::Reset { .. } | PrivilegedAction::AdjustCount { .. } = action {
+        PauseState::ensure_not_paused(deps.storage, PausableOperation::Reset)?;
+    }
+
+    let res = match action {
+        // Returned as is to keep typed errors like `ContractError::CountOverflow`
+        PrivilegedAction::Reset { count, counter_id } => return reset::perform_reset(deps, env, actor_addr, count, counter_id),
+        PrivilegedAction::AdjustCount { count_change, counter_id } => return adjust_count::perform_adjust_count(deps, env, actor_addr, count_change, counter_id),
+        PrivilegedAction::UpdateConfig { counter_policy } => config::perform_update_config(deps, env, actor_addr, counter_policy),
+        PrivilegedAction::UpdateRateLimitPolicy { rate_limit_policy } => config::perform_update_rate_limit_policy(deps, env, actor_addr, rate_limit_policy),
+        PrivilegedAction::UpdatePermitPolicy { permit_policy } => config::perform_update_permit_policy(deps, env, actor_addr, permit_policy),
+        PrivilegedAction::SetCountChangeApprovalThreshold { threshold } => config::perform_set_count_change_approval_threshold(deps, env, actor_addr, threshold),
+        PrivilegedAction::GrantRole { address, role } => roles::perform_grant_role(deps, env, actor_addr, address, role),
+        PrivilegedAction::RevokeRole { address, role } => roles::perform_revoke_role(deps, env, actor_addr, address, role),
+        PrivilegedAction::SetApproverSet { approvers, threshold, proposal_ttl_in_seconds } => {
+            multisig::perform_set_approver_set(deps, env, actor_addr, approvers, threshold, proposal_ttl_in_seconds)
+        },
+        PrivilegedAction::SetTimelockDelay { delay_in_seconds } => timelock::perform_set_timelock_delay(deps, env, actor_addr, delay_in_seconds),
+        PrivilegedAction::CancelQueuedAction { queued_action_id } => timelock::perform_cancel_queued_action(deps, env, actor_addr, queued_action_id),
+    };
+
+    res.map_err(ContractError::from)
+}
+
+/// Same as `perform` but delayed when timelock applies
+/// Caller must check authorization
+pub fn perform_or_queue(deps: DepsMut, env: Env, actor_addr: Addr, action: PrivilegedAction) -> Result<Response, ContractError> {
+    let delay_in_seconds = TimelockManager::get_delay_in_seconds(deps.storage)?;
+    if delay_in_seconds == 0 || !action.is_timelocked() {
+        return perform(deps, env, actor_addr, action);
+    }
+
+    // Fail early instead of when executed
+    validate(deps.api, &action)?;
+    let queued_action = QueuedAction {
+        queued_by: actor_addr.clone(),
+        action,
+        queued_at: env.block.time,
+        execute_after: env.block.time.plus_seconds(delay_in_seconds),
+    };
+    let queued_action_id = TimelockManager::add_queued_action(deps.storage, &env, queued_action.clone(), None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::QueueAction,
+        target: Some(queued_action_id),
+        old_value: None,
+        new_value: Some(to_audit_value(&queued_action.action)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("action queued successfully");
+    Ok(Response::default())
+}
+
+pub fn validate(api: &dyn Api, action: &PrivilegedAction) -> StdResult<()> {
+    match action {
+        // Personal counters are managed by their owners only, see `CountersManager::ensure_can_manage`
+        PrivilegedAction::Reset { counter_id, .. } |
+        PrivilegedAction::AdjustCount { counter_id, .. } => {
+            if CountersManager::is_personal_counter_id(&CountersManager::resolve_counter_id(counter_id.clone())) {
+                return Err(StdError::generic_err("Counter not found"));
+            }
+            Ok(())
+        },
+        PrivilegedAction::UpdateConfig { counter_policy } => counter_policy.validate(),
+        PrivilegedAction::GrantRole { address, .. } |
+        PrivilegedAction::RevokeRole { address, .. } => api.addr_validate(address).map(|_| ()),
+        PrivilegedAction::SetApproverSet { approvers, threshold, proposal_ttl_in_seconds } => {
+            multisig::build_approver_set(api, approvers, *threshold, *proposal_ttl_in_seconds).map(|_| ())
+        },
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,54 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult, Timestamp};
+use crate::state::read_grants::{ReadGrant, ReadGrantsManager, ReadScope};
+
+pub fn try_grant_read_access(deps: DepsMut, env: Env, info: MessageInfo, grantee: String, scopes: Vec<ReadScope>, expires_at_in_ms: Option<u64>) -> StdResult<Response> {
+    let grantee_addr = deps.api.addr_validate(&grantee)?;
+    if scopes.is_empty() {
+        return Err(StdError::generic_err("At least one scope is required, use RevokeReadAccess to remove access"));
+    }
+    let expires_at = expires_at_in_ms.map(|ms| Timestamp::from_nanos(ms.saturating_mul(1_000_000)));
+    if expires_at.is_some_and(|expires_at| expires_at <= env.block.time) {
+        return Err(StdError::generic_err("Expiry must be in the future"));
+    }
+
+    ReadGrantsManager::grant(deps.storage, &info.sender, &grantee_addr, &ReadGrant {
+        scopes,
+        expires_at,
+        granted_at: env.block.time,
+    })?;
+
+    deps.api.debug("read access granted successfully");
+    Ok(Response::default())
+}
+
+pub fn try_revoke_read_access(deps: DepsMut, _env: Env, info: MessageInfo, grantee: String) -> StdResult<Response> {
+    let grantee_addr = deps.api.addr_validate(&grantee)?;
+    ReadGrantsManager::revoke(deps.storage, &info.sender, &grantee_addr)?;
+
+    deps.api.debug("read access revoked successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{Addr};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn grant_n_revoke_read_access_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let past_in_ms = env.block.time.nanos() / 1_000_000 - 1;
+
+        assert!(try_grant_read_access(deps.as_mut(), env.clone(), mock_info("owner", &[]), "grantee".to_string(), vec![], None).is_err());
+        assert!(try_grant_read_access(deps.as_mut(), env.clone(), mock_info("owner", &[]), "grantee".to_string(), vec![ReadScope::History], Some(past_in_ms)).is_err());
+        let _res = try_grant_read_access(deps.as_mut(), env.clone(), mock_info("owner", &[]), "grantee".to_string(), vec![ReadScope::History], None)?;
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &Addr::unchecked("owner"), &Addr::unchecked("grantee"), ReadScope::History).is_ok());
+
+        let _res = try_revoke_read_access(deps.as_mut(), env.clone(), mock_info("owner", &[]), "grantee".to_string())?;
+        assert!(ReadGrantsManager::get_grant(deps.as_ref().storage, &Addr::unchecked("owner"), &Addr::unchecked("grantee")).is_none());
+
+        Ok(())
+    }
+}
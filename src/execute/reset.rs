@@ -1,17 +1,56 @@
-use cosmwasm_std::{DepsMut, MessageInfo, Response, StdError, StdResult};
-use crate::state::{STATE, CONFIG};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint64};
+use crate::error::ContractError;
+use crate::state::Int64;
+use crate::state::counters::{CountersManager, ManageAuthority};
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use crate::state::user_count_update_history::{UserCountUpdateHistoryManager, UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
+use super::privileged_action;
 
-pub fn try_reset(deps: DepsMut, info: MessageInfo, count: i32) -> StdResult<Response> {
+pub fn try_reset(deps: DepsMut, env: Env, info: MessageInfo, count: Int64, counter_id: Option<String>) -> Result<Response, ContractError> {
     let sender_address = info.sender.clone();
-    let config = CONFIG.load(deps.storage)?;
-    if sender_address != config.contract_manager {
-        return Err(StdError::generic_err("Only the owner can reset count"));
+    let authority = CountersManager::ensure_can_manage(deps.storage, &CountersManager::resolve_counter_id(counter_id.clone()), &sender_address)?;
+
+    let action = PrivilegedAction::Reset { count, counter_id };
+    match authority {
+        ManageAuthority::Capability |
+        ManageAuthority::CounterManager => privileged_action::perform_or_queue(deps, env, sender_address, action),
+        // Owners act on their own data only, no global multisig or timelock
+        ManageAuthority::PersonalCounterOwner => privileged_action::perform(deps, env, sender_address, action),
     }
-    let mut state = STATE.load(deps.storage)?;
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_reset(deps: DepsMut, env: Env, sender_address: Addr, count: Int64, counter_id: Option<String>) -> Result<Response, ContractError> {
+    let resolved_counter_id = CountersManager::resolve_counter_id(counter_id.clone());
+    let mut state = CountersManager::get_state(deps.storage, &resolved_counter_id)?;
+    let old_count = state.count;
     state.count = count;
-    state.count_reset_count += 1;
+    state.count_reset_count = state.count_reset_count.checked_add(Uint64::one()).map_err(|_| ContractError::CountOverflow)?;
+
+    CountersManager::save_state(deps.storage, &resolved_counter_id, &state)?;
 
-    STATE.save(deps.storage, &state)?;
+    UserCountUpdateHistoryManager::add_entry(deps.storage, &env, &resolved_counter_id, UserCountUpdateHistoryEntry{
+        user_addr: sender_address.clone(),
+        count_change: Int64::new(count.i64().checked_sub(old_count.i64()).ok_or(ContractError::CountOverflow)?),
+        resulting_count: Some(state.count),
+        kind: UserCountUpdateHistoryEntryKind::Reset {
+            previous_count: old_count,
+            new_count: state.count,
+        },
+        created_at: env.block.time,
+        // Resets are never public
+        marked_as_public_at: None,
+    }, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr: sender_address,
+        action: AuditAction::ResetCount,
+        target: counter_id,
+        old_value: Some(to_audit_value(&old_count.i64())?),
+        new_value: Some(to_audit_value(&count.i64())?),
+        created_at: env.block.time,
+    }, None)?;
 
     deps.api.debug("count reset successfully");
     Ok(Response::default())
@@ -20,10 +59,12 @@ pub fn try_reset(deps: DepsMut, info: MessageInfo, count: i32) -> StdResult<Resp
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cosmwasm_std::{Coin, Uint128};
+    use cosmwasm_std::{Coin, StdResult, Uint128, from_binary};
     use cosmwasm_std::testing::{mock_dependencies_with_balance, mock_env, mock_info};
-    use crate::msg::{InstantiateMsg};
+    use crate::msg::{ExecuteAnswer, ExecuteMsg, InstantiateMsg};
     use crate::state::State;
+    use crate::state::counters::{CounterTarget, DEFAULT_COUNTER_ID};
+    use crate::state::timelock::{TimelockManager};
 
     #[test]
     fn reset_works() -> StdResult<()> {
@@ -40,10 +81,10 @@ mod tests {
         );
         let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
         let _res = crate::instantiate(deps.as_mut(), mock_env(), info, init_msg)?;
-        assert!(STATE.save(deps.as_mut().storage, &State {
-            count: 17,
-            count_increment_count: 17,
-            count_reset_count: 0,
+        assert!(CountersManager::save_state(deps.as_mut().storage, DEFAULT_COUNTER_ID, &State {
+            count: Int64::new(17),
+            count_increment_count: Uint64::new(17),
+            count_reset_count: Uint64::new(0),
         }).is_ok());
 
         let info = mock_info(
@@ -53,19 +94,40 @@ mod tests {
                 amount: Uint128::new(2),
             }],
         );
-        let _res = try_reset(deps.as_mut(), info, 6)?;
+        let _res = try_reset(deps.as_mut(), mock_env(), info, Int64::new(6), None)?;
 
         // should reset count to provided value
-        let state = STATE.load(deps.as_ref().storage);
+        let state = CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID);
         assert_eq!(
             state,
             Ok(State {
-                count: 6,
-                count_increment_count: 17,
-                count_reset_count: 1,
+                count: Int64::new(6),
+                count_increment_count: Uint64::new(17),
+                count_reset_count: Uint64::new(1),
             })
         );
 
+        // should leave a trace
+        assert_eq!(
+            AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, None)?,
+            vec![AuditLogEntry{
+                actor_addr: Addr::unchecked("owner"),
+                action: AuditAction::ResetCount,
+                target: None,
+                old_value: Some("17".to_string()),
+                new_value: Some("6".to_string()),
+                created_at: mock_env().block.time,
+            }],
+        );
+        // should show up in history
+        assert_eq!(
+            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, Addr::unchecked("owner"), 0, 1, false, None)[0].kind,
+            UserCountUpdateHistoryEntryKind::Reset {
+                previous_count: Int64::new(17),
+                new_count: Int64::new(6),
+            },
+        );
+
         Ok(())
     }
 
@@ -92,19 +154,69 @@ mod tests {
                 amount: Uint128::new(2),
             }],
         );
-        assert!(try_reset(deps.as_mut(), info, 6).is_err());
+        assert!(try_reset(deps.as_mut(), mock_env(), info, Int64::new(6), None).is_err());
 
         // State unchanged
-        let state = STATE.load(deps.as_ref().storage);
+        let state = CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID);
         assert_eq!(
             state,
             Ok(State {
-                count: 17,
-                count_increment_count: 0,
-                count_reset_count: 0,
+                count: Int64::new(17),
+                count_increment_count: Uint64::new(0),
+                count_reset_count: Uint64::new(0),
             })
         );
 
         Ok(())
     }
+
+    #[test]
+    fn personal_counter_reset_works() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: Some(3), mark_history_as_public: None, counter_id: None, target: Some(CounterTarget::Personal) };
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("user", &[]), increment_msg)?;
+        let personal_counter_id = CountersManager::personal_counter_id(&Addr::unchecked("user"));
+        let _res = crate::execute::timelock::try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+        let _res = crate::execute::multisig::try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, None)?;
+
+        // Others cannot reset it, even with ResetCount capability
+        assert!(try_reset(deps.as_mut(), mock_env(), mock_info("owner", &[]), Int64::new(6), Some(personal_counter_id.clone())).is_err());
+        assert!(crate::execute::multisig::try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::Reset { count: Int64::new(6), counter_id: Some(personal_counter_id.clone()) }).is_err());
+
+        // Owner resets right away, not affected by multisig or timelock
+        let reset_msg = ExecuteMsg::Reset { count: Int64::new(6), counter_id: Some(personal_counter_id.clone()) };
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("user", &[]), reset_msg)?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &personal_counter_id)?.count, Int64::new(6));
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_counter_manager_reset_not_skipping_timelock_or_multisig() -> StdResult<()> {
+        let mut deps = mock_dependencies_with_balance(&[]);
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let res = crate::execute::counters::try_create_counter(deps.as_mut(), mock_env(), mock_info("owner", &[]), "Team".to_string(), Int64::new(10), Some("manager".to_string()))?;
+        let ExecuteAnswer::CreateCounter { counter_id } = from_binary(&res.data.unwrap())? else {
+            panic!("unexpected answer");
+        };
+        let reset_msg = ExecuteMsg::Reset { count: Int64::new(6), counter_id: Some(counter_id.clone()) };
+
+        // Queued like any other reset
+        let _res = crate::execute::timelock::try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("manager", &[]), reset_msg.clone())?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &counter_id)?.count, Int64::new(10));
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 1);
+
+        // Proposal required like any other reset
+        let _res = crate::execute::multisig::try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, None)?;
+        assert!(crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("manager", &[]), reset_msg).is_err());
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &counter_id)?.count, Int64::new(10));
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 1);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,87 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::state::roles::{Capability, Role, RolesManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+
+pub fn try_grant_role(deps: DepsMut, env: Env, info: MessageInfo, address: String, role: Role) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageRoles)?;
+
+    perform_grant_role(deps, env, info.sender, address, role)
+}
+
+/// Also used by approved multisig proposals, caller must check authorization
+pub fn perform_grant_role(deps: DepsMut, env: Env, actor_addr: Addr, address: String, role: Role) -> StdResult<Response> {
+    let addr = deps.api.addr_validate(&address)?;
+    let old_roles = RolesManager::get_roles(deps.storage, &addr);
+    RolesManager::grant_role(deps.storage, &addr, role)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::GrantRole,
+        target: Some(addr.to_string()),
+        old_value: Some(to_audit_value(&old_roles)?),
+        new_value: Some(to_audit_value(&RolesManager::get_roles(deps.storage, &addr))?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("role granted successfully");
+    Ok(Response::default())
+}
+
+pub fn try_revoke_role(deps: DepsMut, env: Env, info: MessageInfo, address: String, role: Role) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageRoles)?;
+
+    perform_revoke_role(deps, env, info.sender, address, role)
+}
+
+/// Also used by approved multisig proposals, caller must check authorization
+pub fn perform_revoke_role(deps: DepsMut, env: Env, actor_addr: Addr, address: String, role: Role) -> StdResult<Response> {
+    let addr = deps.api.addr_validate(&address)?;
+    let old_roles = RolesManager::get_roles(deps.storage, &addr);
+    RolesManager::revoke_role(deps.storage, &addr, role)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::RevokeRole,
+        target: Some(addr.to_string()),
+        old_value: Some(to_audit_value(&old_roles)?),
+        new_value: Some(to_audit_value(&RolesManager::get_roles(deps.storage, &addr))?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("role revoked successfully");
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+
+    #[test]
+    fn grant_n_revoke_role_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 0, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        // Only those who can manage roles can grant
+        assert!(try_grant_role(deps.as_mut(), mock_env(), mock_info("creator", &[]), "admin".to_string(), Role::Admin).is_err());
+        let _res = try_grant_role(deps.as_mut(), mock_env(), mock_info("owner", &[]), "admin".to_string(), Role::Admin)?;
+        // Admin can manage roles too
+        let _res = try_grant_role(deps.as_mut(), mock_env(), mock_info("admin", &[]), "auditor".to_string(), Role::Auditor)?;
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &Addr::unchecked("auditor")), vec![Role::Auditor]);
+
+        // Auditor cannot manage roles
+        assert!(try_revoke_role(deps.as_mut(), mock_env(), mock_info("auditor", &[]), "admin".to_string(), Role::Admin).is_err());
+        let _res = try_revoke_role(deps.as_mut(), mock_env(), mock_info("owner", &[]), "auditor".to_string(), Role::Auditor)?;
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &Addr::unchecked("auditor")), vec![]);
+
+        let audit_log_entries = AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, None)?;
+        assert_eq!(audit_log_entries[0].action, AuditAction::RevokeRole);
+        assert_eq!(audit_log_entries[0].target, Some("auditor".to_string()));
+        assert_eq!(audit_log_entries[0].old_value, Some("[\"auditor\"]".to_string()));
+        assert_eq!(audit_log_entries[0].new_value, Some("[]".to_string()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, StdError, StdResult};
+use crate::error::ContractError;
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::timelock::{TimelockManager};
+use crate::state::audit_log::{AuditAction, AuditLogEntry, AuditLogManager, to_audit_value};
+use super::privileged_action;
+
+pub fn try_set_timelock_delay(deps: DepsMut, env: Env, info: MessageInfo, delay_in_seconds: u64) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    // Changing delay is delayed too, so it cannot be bypassed
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::SetTimelockDelay { delay_in_seconds }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals and queued actions, caller must check authorization
+pub fn perform_set_timelock_delay(deps: DepsMut, env: Env, actor_addr: Addr, delay_in_seconds: u64) -> StdResult<Response> {
+    let old_delay_in_seconds = TimelockManager::get_delay_in_seconds(deps.storage)?;
+    TimelockManager::set_delay_in_seconds(deps.storage, delay_in_seconds)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::SetTimelockDelay,
+        target: None,
+        old_value: Some(to_audit_value(&old_delay_in_seconds)?),
+        new_value: Some(to_audit_value(&delay_in_seconds)?),
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("timelock delay updated successfully");
+    Ok(Response::default())
+}
+
+pub fn try_cancel_queued_action(deps: DepsMut, env: Env, info: MessageInfo, queued_action_id: String) -> StdResult<Response> {
+    RolesManager::ensure_capability(deps.storage, &info.sender, Capability::ManageConfig)?;
+
+    // Not delayed, cancelling only keeps things as they are
+    privileged_action::perform_or_queue(deps, env, info.sender, PrivilegedAction::CancelQueuedAction { queued_action_id }).map_err(StdError::from)
+}
+
+/// Also used by approved multisig proposals, caller must check authorization
+pub fn perform_cancel_queued_action(deps: DepsMut, env: Env, actor_addr: Addr, queued_action_id: String) -> StdResult<Response> {
+    let queued_action = TimelockManager::get_queued_action(deps.storage, &queued_action_id, None).
+        ok_or_else(|| StdError::generic_err("Queued action not found"))?;
+    TimelockManager::remove_queued_action(deps.storage, &queued_action_id, None)?;
+
+    AuditLogManager::add_entry(deps.storage, &env, AuditLogEntry{
+        actor_addr,
+        action: AuditAction::CancelQueuedAction,
+        target: Some(queued_action_id),
+        old_value: Some(to_audit_value(&queued_action.action)?),
+        new_value: None,
+        created_at: env.block.time,
+    }, None)?;
+
+    deps.api.debug("queued action cancelled successfully");
+    Ok(Response::default())
+}
+
+/// Anyone can execute once the delay passed
+pub fn try_execute_queued_action(deps: DepsMut, env: Env, _info: MessageInfo, queued_action_id: String) -> Result<Response, ContractError> {
+    let queued_action = TimelockManager::get_queued_action(deps.storage, &queued_action_id, None).
+        ok_or_else(|| StdError::generic_err("Queued action not found"))?;
+    if !queued_action.is_executable(&env) {
+        return Err(StdError::generic_err(format!(
+            "Queued action cannot be executed before {} (unix time in seconds)",
+            queued_action.execute_after.seconds(),
+        )).into());
+    }
+    TimelockManager::remove_queued_action(deps.storage, &queued_action_id, None)?;
+
+    // Recorded as done by whoever queued it
+    privileged_action::perform(deps, env, queued_action.queued_by, queued_action.action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use crate::msg::{InstantiateMsg};
+    use crate::state::counters::{CountersManager, DEFAULT_COUNTER_ID};
+    use crate::state::{Int64, PAUSE_STATE, PauseState};
+
+    #[test]
+    fn timelocked_reset_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+
+        assert!(try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("creator", &[]), 60).is_err());
+        // Not delayed when timelock disabled
+        let _res = try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+        assert_eq!(TimelockManager::get_delay_in_seconds(deps.as_ref().storage)?, 60);
+
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("owner", &[]), crate::msg::ExecuteMsg::Reset { count: Int64::new(0), counter_id: None })?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+        let (queued_action_id, _queued_action) = TimelockManager::get_queued_actions(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+
+        assert!(try_execute_queued_action(deps.as_mut(), mock_env(), mock_info("anyone", &[]), queued_action_id.clone()).is_err());
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(60);
+        let _res = try_execute_queued_action(deps.as_mut(), env, mock_info("anyone", &[]), queued_action_id.clone())?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(0));
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn queued_reset_respects_pause() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let _res = try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("owner", &[]), crate::msg::ExecuteMsg::Reset { count: Int64::new(0), counter_id: None })?;
+        let (queued_action_id, _queued_action) = TimelockManager::get_queued_actions(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+        PAUSE_STATE.save(deps.as_mut().storage, &PauseState { reset_paused: true, ..Default::default() })?;
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(60);
+        assert!(try_execute_queued_action(deps.as_mut(), env, mock_info("anyone", &[]), queued_action_id).is_err());
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, DEFAULT_COUNTER_ID)?.count, Int64::new(17));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel_queued_action_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let _res = try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+
+        // Disabling is delayed too
+        let _res = try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 0)?;
+        assert_eq!(TimelockManager::get_delay_in_seconds(deps.as_ref().storage)?, 60);
+        let (queued_action_id, _queued_action) = TimelockManager::get_queued_actions(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+
+        assert!(try_cancel_queued_action(deps.as_mut(), mock_env(), mock_info("creator", &[]), queued_action_id.clone()).is_err());
+        let _res = try_cancel_queued_action(deps.as_mut(), mock_env(), mock_info("owner", &[]), queued_action_id.clone())?;
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(60);
+        assert!(try_execute_queued_action(deps.as_mut(), env, mock_info("anyone", &[]), queued_action_id).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancel_queued_action_requires_proposal_under_multisig() -> StdResult<()> {
+        use crate::execute::multisig::{try_approve_proposal, try_propose_privileged_action, try_set_approver_set};
+        use crate::state::multisig::{MultisigManager};
+
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let _res = try_set_timelock_delay(deps.as_mut(), mock_env(), mock_info("owner", &[]), 60)?;
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("owner", &[]), crate::msg::ExecuteMsg::Reset { count: Int64::new(0), counter_id: None })?;
+        let (queued_action_id, _queued_action) = TimelockManager::get_queued_actions(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+        let _res = try_set_approver_set(deps.as_mut(), mock_env(), mock_info("owner", &[]), vec!["approver1".to_string(), "approver2".to_string()], 2, None)?;
+
+        let cancel_msg = crate::msg::ExecuteMsg::CancelQueuedAction { queued_action_id: queued_action_id.clone() };
+        assert!(crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("owner", &[]), cancel_msg).is_err());
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 1);
+
+        let _res = try_propose_privileged_action(deps.as_mut(), mock_env(), mock_info("approver1", &[]), PrivilegedAction::CancelQueuedAction { queued_action_id: queued_action_id.clone() })?;
+        let (proposal_id, _proposal) = MultisigManager::get_proposals(deps.as_ref().storage, 0, 1, false, None)?.remove(0);
+        let _res = try_approve_proposal(deps.as_mut(), mock_env(), mock_info("approver2", &[]), proposal_id)?;
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, None)?, 0);
+
+        Ok(())
+    }
+}
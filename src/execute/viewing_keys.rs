@@ -0,0 +1,35 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult, to_binary};
+use crate::msg::ExecuteAnswer;
+use crate::state::viewing_keys::{ViewingKeysManager};
+
+pub fn try_create_viewing_key(deps: DepsMut, env: Env, info: MessageInfo, entropy: String) -> StdResult<Response> {
+    let key = ViewingKeysManager::create(deps.storage, &env, &info.sender, &entropy)?;
+
+    Ok(Response::new().set_data(to_binary(&ExecuteAnswer::CreateViewingKey { key })?))
+}
+
+pub fn try_set_viewing_key(deps: DepsMut, _env: Env, info: MessageInfo, key: String) -> StdResult<Response> {
+    ViewingKeysManager::set(deps.storage, &info.sender, &key)?;
+
+    Ok(Response::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn create_viewing_key_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+
+        let res = try_create_viewing_key(deps.as_mut(), mock_env(), mock_info("user1", &[]), "entropy".to_string())?;
+        let ExecuteAnswer::CreateViewingKey { key } = from_binary(&res.data.unwrap())? else {
+            panic!("unexpected answer");
+        };
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &mock_info("user1", &[]).sender, &key).is_ok());
+
+        Ok(())
+    }
+}
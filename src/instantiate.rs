@@ -1,6 +1,7 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult, Uint64};
 use crate::msg::{InstantiateMsg};
-use crate::state::{Config, STATE, CONFIG, State};
+use crate::state::{Config, CounterPolicy, CONFIG, Int64, State};
+use crate::state::counters::{CountersManager};
 use crate::state::schema_migrations::{SCHEMA_MIGRATION_VERSIONS};
 
 pub fn perform_instantiate(
@@ -12,20 +13,21 @@ pub fn perform_instantiate(
     let contract_manager_addr = deps.api.addr_validate(&msg.contract_manager)?;
 
     let state = State {
-        count: msg.count,
-        count_increment_count: 0,
-        count_reset_count: 0,
+        count: Int64::from(msg.count),
+        count_increment_count: Uint64::zero(),
+        count_reset_count: Uint64::zero(),
     };
 
     let config = Config {
         contract_manager: contract_manager_addr,
-        contract_address: env.contract.address,
+        contract_address: env.contract.address.clone(),
+        counter_policy: CounterPolicy::default(),
     };
 
     // Add run migration versions later
     let schema_migrations = crate::migrate::all_migration_version();
 
-    STATE.save(deps.storage, &state)?;
+    CountersManager::add_default_counter(deps.storage, &env, &state)?;
     CONFIG.save(deps.storage, &config)?;
     SCHEMA_MIGRATION_VERSIONS.save(deps.storage, &schema_migrations)?;
 
@@ -59,9 +61,9 @@ mod tests {
         assert_eq!(0, res.messages.len());
 
         // it worked, let's query the state
-        let res = query_dispatch(deps.as_ref(), mock_env(), QueryMsg::GetCount {})?;
+        let res = query_dispatch(deps.as_ref(), mock_env(), QueryMsg::GetCount { counter_id: None })?;
         let value: CountResponse = from_binary(&res)?;
-        assert_eq!(17, value.count);
+        assert_eq!(Int64::new(17), value.count);
 
         let config = CONFIG.load(deps.as_ref().storage);
         assert_eq!(
@@ -69,6 +71,7 @@ mod tests {
             Ok(Config {
                 contract_manager: contract_manager_addr,
                 contract_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+                counter_policy: CounterPolicy::default(),
             })
         );
 
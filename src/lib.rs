@@ -9,6 +9,7 @@ pub mod msg;
 pub mod instantiate;
 pub mod query;
 pub mod execute;
+pub mod error;
 mod migrate;
 
 #[entry_point]
@@ -27,7 +28,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[entry_point]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: msg::ExecuteMsg) -> StdResult<Response> {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: msg::ExecuteMsg) -> Result<Response, error::ContractError> {
     execute_dispatch(deps, env, info, msg)
 }
 
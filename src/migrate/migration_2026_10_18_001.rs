@@ -0,0 +1,83 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdResult, Addr, Timestamp};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::user_count_update_history::{UserCountUpdateHistoryEntryKind};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: i32,
+    pub kind: UserCountUpdateHistoryEntryKind,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+pub static NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, NewUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct OldUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: i32,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+pub static OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, OldUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+
+pub fn execute(deps: &mut DepsMut, _env: Env) -> StdResult<Response> {
+    // Collect first since storage cannot be written while iterating
+    let old_entries = OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, OldUserCountUpdateHistoryEntry)>>>()?;
+
+    // Only increments were recorded before
+    for (entry_id, old_entry) in old_entries {
+        NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.storage, &entry_id, &NewUserCountUpdateHistoryEntry {
+            user_addr: old_entry.user_addr,
+            count_change: old_entry.count_change,
+            kind: UserCountUpdateHistoryEntryKind::Increment,
+            created_at: old_entry.created_at,
+            marked_as_public_at: old_entry.marked_as_public_at,
+        })?;
+    }
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn migrate_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+
+        let old_entry = OldUserCountUpdateHistoryEntry {
+            user_addr: Addr::unchecked("user"),
+            count_change: 2,
+            created_at: Default::default(),
+            marked_as_public_at: None,
+        };
+        OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.as_mut().storage, &"entry_id".to_string(), &old_entry)?;
+
+        let _res = execute(&mut deps.as_mut(), mock_env())?;
+
+        assert_eq!(
+            NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.get(deps.as_ref().storage, &"entry_id".to_string()),
+            Some(NewUserCountUpdateHistoryEntry {
+                user_addr: Addr::unchecked("user"),
+                count_change: 2,
+                kind: UserCountUpdateHistoryEntryKind::Increment,
+                created_at: Default::default(),
+                marked_as_public_at: None,
+            })
+        );
+        assert_eq!(NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.get_len(deps.as_ref().storage)?, 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdResult, Addr, Timestamp};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use secret_toolkit::storage::{Item, Keymap, Keyset};
+use secret_toolkit::serialization::{Json};
+
+// Existing state becomes the default counter
+const DEFAULT_COUNTER_ID: &str = "default";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct State {
+    pub count: i32,
+    pub count_increment_count: i32,
+    pub count_reset_count: i32,
+}
+
+pub static OLD_STATE: Item<State> = Item::new(b"state");
+pub static NEW_COUNTER_STATE_STORE: Keymap<String, State, Json> = Keymap::new(b"counters__state");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewCounterInfo {
+    pub name: String,
+    pub manager: Option<Addr>,
+    pub created_at: Timestamp,
+}
+
+pub static NEW_COUNTER_INFO_STORE: Keymap<String, NewCounterInfo, Json> = Keymap::new(b"counters__info");
+
+// Only fields needed for indexing
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct UserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+pub static USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, UserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+// Old indexes without counter ID are left as is, no longer read
+pub static NEW_COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"user_count_update_history__counter_id_index");
+pub static NEW_USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"user_count_update_history__user_addr_index");
+pub static NEW_GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"global_public_user_count_update_history_index_store");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct UserStatisticData {
+    pub count_increment_count: u32,
+}
+
+// Counter ID as suffix for new store
+pub static USER_STATISTIC_DATA_STORE: Keymap<Addr, UserStatisticData, Json> = Keymap::new(b"user_statistic_data");
+
+pub fn execute(deps: &mut DepsMut, env: Env) -> StdResult<Response> {
+    let counter_id = DEFAULT_COUNTER_ID.to_string();
+
+    let old_state = OLD_STATE.load(deps.storage)?;
+    NEW_COUNTER_INFO_STORE.insert(deps.storage, &counter_id, &NewCounterInfo {
+        name: "Default".to_string(),
+        manager: None,
+        created_at: env.block.time,
+    })?;
+    NEW_COUNTER_STATE_STORE.insert(deps.storage, &counter_id, &old_state)?;
+    OLD_STATE.remove(deps.storage);
+
+    // Collect first since storage cannot be written while iterating
+    // Iterated in insertion order, so new indexes are in the same order as old ones
+    let entries = USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, UserCountUpdateHistoryEntry)>>>()?;
+    for (entry_id, entry) in entries {
+        NEW_COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).insert(deps.storage, &entry_id)?;
+        NEW_USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).add_suffix(entry.user_addr.as_bytes()).
+            insert(deps.storage, &entry_id)?;
+        if entry.marked_as_public_at.is_some() {
+            NEW_GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).insert(deps.storage, &entry_id)?;
+        }
+    }
+
+    let user_statistic_data_entries = USER_STATISTIC_DATA_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(Addr, UserStatisticData)>>>()?;
+    for (user_addr, data) in user_statistic_data_entries {
+        USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes()).insert(deps.storage, &user_addr, &data)?;
+        USER_STATISTIC_DATA_STORE.remove(deps.storage, &user_addr)?;
+    }
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn migrate_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let user_addr = Addr::unchecked("user");
+
+        let old_state = State {
+            count: 3,
+            count_increment_count: 2,
+            count_reset_count: 1,
+        };
+        OLD_STATE.save(deps.as_mut().storage, &old_state)?;
+        USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.as_mut().storage, &"entry1".to_string(), &UserCountUpdateHistoryEntry {
+            user_addr: user_addr.clone(),
+            marked_as_public_at: Some(Default::default()),
+        })?;
+        USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.as_mut().storage, &"entry2".to_string(), &UserCountUpdateHistoryEntry {
+            user_addr: user_addr.clone(),
+            marked_as_public_at: None,
+        })?;
+        USER_STATISTIC_DATA_STORE.insert(deps.as_mut().storage, &user_addr, &UserStatisticData { count_increment_count: 2 })?;
+
+        let _res = execute(&mut deps.as_mut(), mock_env())?;
+
+        let counter_id = DEFAULT_COUNTER_ID.to_string();
+        assert_eq!(NEW_COUNTER_STATE_STORE.get(deps.as_ref().storage, &counter_id), Some(old_state));
+        assert!(NEW_COUNTER_INFO_STORE.get(deps.as_ref().storage, &counter_id).is_some());
+        assert!(OLD_STATE.may_load(deps.as_ref().storage)?.is_none());
+
+        assert_eq!(
+            NEW_COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).paging(deps.as_ref().storage, 0, 10)?,
+            vec!["entry1".to_string(), "entry2".to_string()],
+        );
+        assert_eq!(
+            NEW_USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).add_suffix(user_addr.as_bytes()).get_len(deps.as_ref().storage)?,
+            2,
+        );
+        assert_eq!(
+            NEW_GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).paging(deps.as_ref().storage, 0, 10)?,
+            vec!["entry1".to_string()],
+        );
+
+        assert_eq!(
+            USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes()).get(deps.as_ref().storage, &user_addr),
+            Some(UserStatisticData { count_increment_count: 2 }),
+        );
+        assert!(USER_STATISTIC_DATA_STORE.get(deps.as_ref().storage, &user_addr).is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdResult, Addr, Timestamp, Uint64};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::Int64;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldState {
+    pub count: i32,
+    pub count_increment_count: i32,
+    pub count_reset_count: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewState {
+    pub count: Int64,
+    pub count_increment_count: Uint64,
+    pub count_reset_count: Uint64,
+}
+
+pub static OLD_COUNTER_STATE_STORE: Keymap<String, OldState, Json> = Keymap::new(b"counters__state");
+pub static NEW_COUNTER_STATE_STORE: Keymap<String, NewState, Json> = Keymap::new(b"counters__state");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldUserStatisticData {
+    pub count_increment_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewUserStatisticData {
+    pub count_increment_count: Uint64,
+}
+
+// Counter ID as suffix
+pub static OLD_USER_STATISTIC_DATA_STORE: Keymap<Addr, OldUserStatisticData, Json> = Keymap::new(b"user_statistic_data");
+pub static NEW_USER_STATISTIC_DATA_STORE: Keymap<Addr, NewUserStatisticData, Json> = Keymap::new(b"user_statistic_data");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OldUserCountUpdateHistoryEntryKind {
+    Increment,
+    Reset {
+        previous_count: i32,
+        new_count: i32,
+    },
+    Adjustment,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: i32,
+    pub kind: OldUserCountUpdateHistoryEntryKind,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NewUserCountUpdateHistoryEntryKind {
+    Increment,
+    Reset {
+        previous_count: Int64,
+        new_count: Int64,
+    },
+    Adjustment,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: Int64,
+    pub kind: NewUserCountUpdateHistoryEntryKind,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+pub static OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, OldUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+pub static NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, NewUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+
+// Tallies are never negative, overflow checks would have aborted
+fn to_new_tally(tally: i32) -> Uint64 {
+    Uint64::new(tally.max(0) as u64)
+}
+
+pub fn execute(deps: &mut DepsMut, _env: Env) -> StdResult<Response> {
+    // Collect first since storage cannot be written while iterating
+    let old_states = OLD_COUNTER_STATE_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, OldState)>>>()?;
+    for (counter_id, old_state) in old_states {
+        NEW_COUNTER_STATE_STORE.insert(deps.storage, &counter_id, &NewState {
+            count: Int64::from(old_state.count),
+            count_increment_count: to_new_tally(old_state.count_increment_count),
+            count_reset_count: to_new_tally(old_state.count_reset_count),
+        })?;
+
+        let old_user_statistic_data_store = OLD_USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes());
+        let new_user_statistic_data_store = NEW_USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes());
+        let old_user_statistic_data_entries = old_user_statistic_data_store.iter(deps.storage)?.
+            collect::<StdResult<Vec<(Addr, OldUserStatisticData)>>>()?;
+        for (user_addr, old_data) in old_user_statistic_data_entries {
+            new_user_statistic_data_store.insert(deps.storage, &user_addr, &NewUserStatisticData {
+                count_increment_count: Uint64::from(old_data.count_increment_count),
+            })?;
+        }
+    }
+
+    let old_entries = OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, OldUserCountUpdateHistoryEntry)>>>()?;
+    for (entry_id, old_entry) in old_entries {
+        NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.storage, &entry_id, &NewUserCountUpdateHistoryEntry {
+            user_addr: old_entry.user_addr,
+            count_change: Int64::from(old_entry.count_change),
+            kind: match old_entry.kind {
+                OldUserCountUpdateHistoryEntryKind::Increment => NewUserCountUpdateHistoryEntryKind::Increment,
+                OldUserCountUpdateHistoryEntryKind::Reset { previous_count, new_count } => NewUserCountUpdateHistoryEntryKind::Reset {
+                    previous_count: Int64::from(previous_count),
+                    new_count: Int64::from(new_count),
+                },
+                OldUserCountUpdateHistoryEntryKind::Adjustment => NewUserCountUpdateHistoryEntryKind::Adjustment,
+            },
+            created_at: old_entry.created_at,
+            marked_as_public_at: old_entry.marked_as_public_at,
+        })?;
+    }
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn migrate_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let counter_id = "default".to_string();
+        let user_addr = Addr::unchecked("user");
+
+        OLD_COUNTER_STATE_STORE.insert(deps.as_mut().storage, &counter_id, &OldState {
+            count: -3,
+            count_increment_count: 2,
+            count_reset_count: 1,
+        })?;
+        OLD_USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes()).insert(deps.as_mut().storage, &user_addr, &OldUserStatisticData {
+            count_increment_count: 2,
+        })?;
+        OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.as_mut().storage, &"entry1".to_string(), &OldUserCountUpdateHistoryEntry {
+            user_addr: user_addr.clone(),
+            count_change: -5,
+            kind: OldUserCountUpdateHistoryEntryKind::Reset {
+                previous_count: 2,
+                new_count: -3,
+            },
+            created_at: Timestamp::from_seconds(1),
+            marked_as_public_at: None,
+        })?;
+
+        let _res = execute(&mut deps.as_mut(), mock_env())?;
+
+        assert_eq!(NEW_COUNTER_STATE_STORE.get(deps.as_ref().storage, &counter_id), Some(NewState {
+            count: Int64::new(-3),
+            count_increment_count: Uint64::new(2),
+            count_reset_count: Uint64::new(1),
+        }));
+        assert_eq!(
+            NEW_USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes()).get(deps.as_ref().storage, &user_addr),
+            Some(NewUserStatisticData { count_increment_count: Uint64::new(2) }),
+        );
+        assert_eq!(NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.get(deps.as_ref().storage, &"entry1".to_string()), Some(NewUserCountUpdateHistoryEntry {
+            user_addr,
+            count_change: Int64::new(-5),
+            kind: NewUserCountUpdateHistoryEntryKind::Reset {
+                previous_count: Int64::new(2),
+                new_count: Int64::new(-3),
+            },
+            created_at: Timestamp::from_seconds(1),
+            marked_as_public_at: None,
+        }));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdError, StdResult, Addr, Timestamp, Uint64};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use secret_toolkit::storage::{Keymap, Keyset};
+use secret_toolkit::serialization::{Json};
+
+use crate::error::ContractError;
+use crate::state::Int64;
+
+// Same interval as `CountCheckpointsManager` at the time of this migration
+const COUNT_CHECKPOINT_INTERVAL: u32 = 100;
+
+// Only fields needed, others are ignored when reading
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CounterState {
+    pub count: Int64,
+    pub count_reset_count: Uint64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CounterInfo {
+    pub created_at: Timestamp,
+}
+
+pub static COUNTER_STATE_STORE: Keymap<String, CounterState, Json> = Keymap::new(b"counters__state");
+pub static COUNTER_INFO_STORE: Keymap<String, CounterInfo, Json> = Keymap::new(b"counters__info");
+// Counter ID as suffix
+pub static COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"user_count_update_history__counter_id_index");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserCountUpdateHistoryEntryKind {
+    Increment,
+    Reset {
+        previous_count: Int64,
+        new_count: Int64,
+    },
+    Adjustment,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: Int64,
+    pub kind: UserCountUpdateHistoryEntryKind,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct NewUserCountUpdateHistoryEntry {
+    pub user_addr: Addr,
+    pub count_change: Int64,
+    pub resulting_count: Option<Int64>,
+    pub kind: UserCountUpdateHistoryEntryKind,
+    pub created_at: Timestamp,
+    pub marked_as_public_at: Option<Timestamp>,
+}
+
+pub static OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, OldUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+pub static NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, NewUserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CountCheckpoint {
+    pub count: Int64,
+    pub history_entry_count: u32,
+    pub created_at: Timestamp,
+}
+
+// Counter ID as suffix
+pub static COUNT_CHECKPOINT_STORE: Keymap<u32, CountCheckpoint, Json> = Keymap::new(b"count_checkpoints__checkpoint");
+
+pub fn execute(deps: &mut DepsMut, env: Env) -> StdResult<Response> {
+    // Collect first since storage cannot be written while iterating
+    let counter_states = COUNTER_STATE_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, CounterState)>>>()?;
+    for (counter_id, counter_state) in counter_states {
+        let counter_info = COUNTER_INFO_STORE.get(deps.storage, &counter_id).
+            ok_or_else(|| StdError::generic_err(format!("Counter info missing for {}", counter_id)))?;
+        let entry_ids = COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).iter(deps.storage)?.
+            collect::<StdResult<Vec<String>>>()?;
+        let entries = entry_ids.iter().
+            map(|id| OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.get(deps.storage, id).ok_or_else(|| StdError::not_found("UserCountUpdateHistoryEntry"))).
+            collect::<StdResult<Vec<OldUserCountUpdateHistoryEntry>>>()?;
+
+        // Resets were not recorded before history entry kinds, those all happened before the first recorded one
+        let is_reset = |entry: &OldUserCountUpdateHistoryEntry| matches!(entry.kind, UserCountUpdateHistoryEntryKind::Reset { .. });
+        let recorded_reset_count = entries.iter().filter(|entry| is_reset(entry)).count() as u64;
+        let first_known_position = if recorded_reset_count == counter_state.count_reset_count.u64() {
+            0
+        } else {
+            entries.iter().position(is_reset).unwrap_or(entries.len())
+        };
+
+        // Walk back from current count since initial counts were not recorded
+        let mut running_count = counter_state.count;
+        let mut resulting_counts = vec![None; entries.len()];
+        for (i, entry) in entries.iter().enumerate().skip(first_known_position).rev() {
+            resulting_counts[i] = Some(running_count);
+            running_count = match entry.kind {
+                UserCountUpdateHistoryEntryKind::Reset { previous_count, .. } => previous_count,
+                _ => Int64::new(running_count.i64().checked_sub(entry.count_change.i64()).ok_or(ContractError::CountOverflow)?),
+            };
+        }
+
+        // Counts before it are unknown, `GetCountAt` treats them like before counter creation
+        let initial_checkpoint = match (first_known_position, entries.get(first_known_position)) {
+            (0, _) => CountCheckpoint {
+                count: running_count,
+                history_entry_count: 0,
+                // Default counter was created by an earlier migration, after its existing entries
+                created_at: entries.first().map_or(counter_info.created_at, |entry| entry.created_at.min(counter_info.created_at)),
+            },
+            // Right after first recorded reset
+            (position, Some(OldUserCountUpdateHistoryEntry { kind: UserCountUpdateHistoryEntryKind::Reset { new_count, .. }, created_at, .. })) => CountCheckpoint {
+                count: *new_count,
+                history_entry_count: position as u32 + 1,
+                created_at: *created_at,
+            },
+            // No recorded reset, only current count is known
+            (position, _) => CountCheckpoint {
+                count: counter_state.count,
+                history_entry_count: position as u32,
+                created_at: env.block.time,
+            },
+        };
+        let checkpoint_store = COUNT_CHECKPOINT_STORE.add_suffix(counter_id.as_bytes());
+        checkpoint_store.insert(deps.storage, &initial_checkpoint.history_entry_count, &initial_checkpoint)?;
+
+        for (i, (entry_id, entry)) in entry_ids.iter().zip(entries).enumerate() {
+            let history_entry_count = i as u32 + 1;
+            if let (0, Some(count)) = (history_entry_count % COUNT_CHECKPOINT_INTERVAL, resulting_counts[i]) {
+                if history_entry_count > initial_checkpoint.history_entry_count {
+                    checkpoint_store.insert(deps.storage, &history_entry_count, &CountCheckpoint {
+                        count,
+                        history_entry_count,
+                        created_at: entry.created_at,
+                    })?;
+                }
+            }
+            NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(deps.storage, entry_id, &NewUserCountUpdateHistoryEntry {
+                user_addr: entry.user_addr,
+                count_change: entry.count_change,
+                resulting_count: resulting_counts[i],
+                kind: entry.kind,
+                created_at: entry.created_at,
+                marked_as_public_at: entry.marked_as_public_at,
+            })?;
+        }
+    }
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    fn insert_old_counter(storage: &mut dyn cosmwasm_std::Storage, counter_id: &str, count: i64, count_reset_count: u64, old_entries: Vec<(Int64, UserCountUpdateHistoryEntryKind)>) -> StdResult<()> {
+        COUNTER_STATE_STORE.insert(storage, &counter_id.to_string(), &CounterState { count: Int64::new(count), count_reset_count: Uint64::new(count_reset_count) })?;
+        COUNTER_INFO_STORE.insert(storage, &counter_id.to_string(), &CounterInfo { created_at: Timestamp::from_seconds(100) })?;
+        for (i, (count_change, kind)) in old_entries.into_iter().enumerate() {
+            let entry_id = format!("{}_entry{}", counter_id, i);
+            OLD_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.insert(storage, &entry_id, &OldUserCountUpdateHistoryEntry {
+                user_addr: Addr::unchecked("user"),
+                count_change,
+                kind,
+                created_at: Timestamp::from_seconds(i as u64 + 1),
+                marked_as_public_at: None,
+            })?;
+            COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).insert(storage, &entry_id)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_resulting_counts(storage: &dyn cosmwasm_std::Storage, counter_id: &str, entry_count: usize) -> Vec<Option<Int64>> {
+        (0..entry_count).
+            map(|i| NEW_USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.get(storage, &format!("{}_entry{}", counter_id, i)).unwrap().resulting_count).
+            collect()
+    }
+
+    #[test]
+    fn migrate_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let counter_id = "default";
+
+        // Started from 1: +3 => 4, reset => 10, -5 => 5
+        insert_old_counter(deps.as_mut().storage, counter_id, 5, 1, vec![
+            (Int64::new(3), UserCountUpdateHistoryEntryKind::Increment),
+            (Int64::new(6), UserCountUpdateHistoryEntryKind::Reset { previous_count: Int64::new(4), new_count: Int64::new(10) }),
+            (Int64::new(-5), UserCountUpdateHistoryEntryKind::Adjustment),
+        ])?;
+
+        let _res = execute(&mut deps.as_mut(), mock_env())?;
+
+        assert_eq!(get_resulting_counts(deps.as_ref().storage, counter_id, 3), vec![Some(Int64::new(4)), Some(Int64::new(10)), Some(Int64::new(5))]);
+        assert_eq!(COUNT_CHECKPOINT_STORE.add_suffix(counter_id.as_bytes()).get(deps.as_ref().storage, &0), Some(CountCheckpoint {
+            count: Int64::new(1),
+            history_entry_count: 0,
+            created_at: Timestamp::from_seconds(1),
+        }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_with_unrecorded_resets_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // One reset unrecorded, somewhere before the recorded one
+        insert_old_counter(deps.as_mut().storage, "recorded_later", 5, 2, vec![
+            (Int64::new(3), UserCountUpdateHistoryEntryKind::Increment),
+            (Int64::new(2), UserCountUpdateHistoryEntryKind::Increment),
+            (Int64::new(3), UserCountUpdateHistoryEntryKind::Reset { previous_count: Int64::new(7), new_count: Int64::new(10) }),
+            (Int64::new(-5), UserCountUpdateHistoryEntryKind::Adjustment),
+        ])?;
+        // No reset recorded at all
+        insert_old_counter(deps.as_mut().storage, "never_recorded", 8, 1, vec![
+            (Int64::new(3), UserCountUpdateHistoryEntryKind::Increment),
+        ])?;
+
+        let _res = execute(&mut deps.as_mut(), env.clone())?;
+
+        // Known from the first recorded reset only
+        assert_eq!(get_resulting_counts(deps.as_ref().storage, "recorded_later", 4), vec![None, None, Some(Int64::new(10)), Some(Int64::new(5))]);
+        let checkpoint_store = COUNT_CHECKPOINT_STORE.add_suffix(b"recorded_later");
+        assert_eq!(checkpoint_store.get_len(deps.as_ref().storage)?, 1);
+        assert_eq!(checkpoint_store.get(deps.as_ref().storage, &3), Some(CountCheckpoint {
+            count: Int64::new(10),
+            history_entry_count: 3,
+            created_at: Timestamp::from_seconds(3),
+        }));
+
+        // Known from migration only
+        assert_eq!(get_resulting_counts(deps.as_ref().storage, "never_recorded", 1), vec![None]);
+        let checkpoint_store = COUNT_CHECKPOINT_STORE.add_suffix(b"never_recorded");
+        assert_eq!(checkpoint_store.get_len(deps.as_ref().storage)?, 1);
+        assert_eq!(checkpoint_store.get(deps.as_ref().storage, &1), Some(CountCheckpoint {
+            count: Int64::new(8),
+            history_entry_count: 1,
+            created_at: env.block.time,
+        }));
+
+        Ok(())
+    }
+}
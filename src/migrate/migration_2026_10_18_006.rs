@@ -0,0 +1,225 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdResult, Addr, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+use crate::state::{Config, CounterPolicy, Int64, PermitPolicy, RateLimitPolicy};
+use crate::state::multisig::{PrivilegedAction, Proposal};
+use crate::state::roles::Role;
+use crate::state::timelock::{QueuedAction};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldCounterPolicy {
+    pub min_step: Option<i32>,
+    pub max_step: Option<i32>,
+    pub count_floor: Option<i32>,
+    pub count_ceiling: Option<i32>,
+    pub allow_negative_steps: bool,
+    pub max_memo_length: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldConfig {
+    pub contract_manager: Addr,
+    pub contract_address: Addr,
+    pub counter_policy: OldCounterPolicy,
+}
+
+pub static OLD_CONFIG: Item<OldConfig> = Item::new(b"config");
+pub static NEW_CONFIG: Item<Config> = Item::new(b"config");
+
+// Same as `PrivilegedAction` except count and policy types
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OldPrivilegedAction {
+    Reset {
+        count: i32,
+        counter_id: Option<String>,
+    },
+    AdjustCount {
+        count_change: i32,
+        counter_id: Option<String>,
+    },
+    UpdateConfig {
+        counter_policy: OldCounterPolicy,
+    },
+    UpdateRateLimitPolicy {
+        rate_limit_policy: RateLimitPolicy,
+    },
+    UpdatePermitPolicy {
+        permit_policy: PermitPolicy,
+    },
+    SetCountChangeApprovalThreshold {
+        threshold: Option<u32>,
+    },
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+    SetApproverSet {
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl_in_seconds: Option<u64>,
+    },
+    SetTimelockDelay {
+        delay_in_seconds: u64,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldProposal {
+    pub proposer_addr: Addr,
+    pub action: OldPrivilegedAction,
+    pub approvals: Vec<Addr>,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct OldQueuedAction {
+    pub queued_by: Addr,
+    pub action: OldPrivilegedAction,
+    pub queued_at: Timestamp,
+    pub execute_after: Timestamp,
+}
+
+pub static OLD_PROPOSAL_STORE: Keymap<String, OldProposal, Json> = Keymap::new(b"multisig__proposal");
+pub static NEW_PROPOSAL_STORE: Keymap<String, Proposal, Json> = Keymap::new(b"multisig__proposal");
+pub static OLD_QUEUED_ACTION_STORE: Keymap<String, OldQueuedAction, Json> = Keymap::new(b"timelock__queued_action");
+pub static NEW_QUEUED_ACTION_STORE: Keymap<String, QueuedAction, Json> = Keymap::new(b"timelock__queued_action");
+
+fn to_new_counter_policy(old_counter_policy: OldCounterPolicy) -> CounterPolicy {
+    CounterPolicy {
+        min_step: old_counter_policy.min_step.map(Int64::from),
+        max_step: old_counter_policy.max_step.map(Int64::from),
+        count_floor: old_counter_policy.count_floor.map(Int64::from),
+        count_ceiling: old_counter_policy.count_ceiling.map(Int64::from),
+        allow_negative_steps: old_counter_policy.allow_negative_steps,
+        max_memo_length: old_counter_policy.max_memo_length,
+    }
+}
+
+fn to_new_action(old_action: OldPrivilegedAction) -> PrivilegedAction {
+    match old_action {
+        OldPrivilegedAction::Reset { count, counter_id } => PrivilegedAction::Reset { count: Int64::from(count), counter_id },
+        OldPrivilegedAction::AdjustCount { count_change, counter_id } => PrivilegedAction::AdjustCount { count_change, counter_id },
+        OldPrivilegedAction::UpdateConfig { counter_policy } => PrivilegedAction::UpdateConfig { counter_policy: to_new_counter_policy(counter_policy) },
+        OldPrivilegedAction::UpdateRateLimitPolicy { rate_limit_policy } => PrivilegedAction::UpdateRateLimitPolicy { rate_limit_policy },
+        OldPrivilegedAction::UpdatePermitPolicy { permit_policy } => PrivilegedAction::UpdatePermitPolicy { permit_policy },
+        OldPrivilegedAction::SetCountChangeApprovalThreshold { threshold } => PrivilegedAction::SetCountChangeApprovalThreshold { threshold },
+        OldPrivilegedAction::GrantRole { address, role } => PrivilegedAction::GrantRole { address, role },
+        OldPrivilegedAction::RevokeRole { address, role } => PrivilegedAction::RevokeRole { address, role },
+        OldPrivilegedAction::SetApproverSet { approvers, threshold, proposal_ttl_in_seconds } => PrivilegedAction::SetApproverSet { approvers, threshold, proposal_ttl_in_seconds },
+        OldPrivilegedAction::SetTimelockDelay { delay_in_seconds } => PrivilegedAction::SetTimelockDelay { delay_in_seconds },
+    }
+}
+
+pub fn execute(deps: &mut DepsMut, _env: Env) -> StdResult<Response> {
+    let old_config = OLD_CONFIG.load(deps.storage)?;
+    NEW_CONFIG.save(deps.storage, &Config {
+        contract_manager: old_config.contract_manager,
+        contract_address: old_config.contract_address,
+        counter_policy: to_new_counter_policy(old_config.counter_policy),
+    })?;
+
+    // Collect first since storage cannot be written while iterating
+    let old_proposals = OLD_PROPOSAL_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, OldProposal)>>>()?;
+    for (proposal_id, old_proposal) in old_proposals {
+        NEW_PROPOSAL_STORE.insert(deps.storage, &proposal_id, &Proposal {
+            proposer_addr: old_proposal.proposer_addr,
+            action: to_new_action(old_proposal.action),
+            approvals: old_proposal.approvals,
+            created_at: old_proposal.created_at,
+            expires_at: old_proposal.expires_at,
+        })?;
+    }
+
+    let old_queued_actions = OLD_QUEUED_ACTION_STORE.iter(deps.storage)?.
+        collect::<StdResult<Vec<(String, OldQueuedAction)>>>()?;
+    for (queued_action_id, old_queued_action) in old_queued_actions {
+        NEW_QUEUED_ACTION_STORE.insert(deps.storage, &queued_action_id, &QueuedAction {
+            queued_by: old_queued_action.queued_by,
+            action: to_new_action(old_queued_action.action),
+            queued_at: old_queued_action.queued_at,
+            execute_after: old_queued_action.execute_after,
+        })?;
+    }
+
+    Ok(Response::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn migrate_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let old_counter_policy = OldCounterPolicy {
+            min_step: Some(1),
+            max_step: Some(10),
+            count_floor: Some(-5),
+            count_ceiling: None,
+            allow_negative_steps: false,
+            max_memo_length: Some(100),
+        };
+        let new_counter_policy = CounterPolicy {
+            min_step: Some(Int64::new(1)),
+            max_step: Some(Int64::new(10)),
+            count_floor: Some(Int64::new(-5)),
+            count_ceiling: None,
+            allow_negative_steps: false,
+            max_memo_length: Some(100),
+        };
+
+        OLD_CONFIG.save(deps.as_mut().storage, &OldConfig {
+            contract_manager: Addr::unchecked("contract_manager"),
+            contract_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+            counter_policy: old_counter_policy.clone(),
+        })?;
+        OLD_PROPOSAL_STORE.insert(deps.as_mut().storage, &"proposal1".to_string(), &OldProposal {
+            proposer_addr: Addr::unchecked("approver1"),
+            action: OldPrivilegedAction::UpdateConfig { counter_policy: old_counter_policy },
+            approvals: vec![Addr::unchecked("approver1")],
+            created_at: env.block.time,
+            expires_at: env.block.time.plus_seconds(60),
+        })?;
+        OLD_QUEUED_ACTION_STORE.insert(deps.as_mut().storage, &"queued_action1".to_string(), &OldQueuedAction {
+            queued_by: Addr::unchecked("contract_manager"),
+            action: OldPrivilegedAction::Reset { count: -3, counter_id: None },
+            queued_at: env.block.time,
+            execute_after: env.block.time.plus_seconds(60),
+        })?;
+        OLD_QUEUED_ACTION_STORE.insert(deps.as_mut().storage, &"queued_action2".to_string(), &OldQueuedAction {
+            queued_by: Addr::unchecked("contract_manager"),
+            action: OldPrivilegedAction::SetTimelockDelay { delay_in_seconds: 0 },
+            queued_at: env.block.time,
+            execute_after: env.block.time.plus_seconds(60),
+        })?;
+
+        let _res = execute(&mut deps.as_mut(), env.clone())?;
+
+        assert_eq!(NEW_CONFIG.load(deps.as_ref().storage)?.counter_policy, new_counter_policy.clone());
+        assert_eq!(
+            NEW_PROPOSAL_STORE.get(deps.as_ref().storage, &"proposal1".to_string()).map(|p| p.action),
+            Some(PrivilegedAction::UpdateConfig { counter_policy: new_counter_policy }),
+        );
+        assert_eq!(
+            NEW_QUEUED_ACTION_STORE.get(deps.as_ref().storage, &"queued_action1".to_string()).map(|a| a.action),
+            Some(PrivilegedAction::Reset { count: Int64::new(-3), counter_id: None }),
+        );
+        assert_eq!(
+            NEW_QUEUED_ACTION_STORE.get(deps.as_ref().storage, &"queued_action2".to_string()).map(|a| a.action),
+            Some(PrivilegedAction::SetTimelockDelay { delay_in_seconds: 0 }),
+        );
+
+        Ok(())
+    }
+}
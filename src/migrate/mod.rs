@@ -6,6 +6,12 @@ use crate::state::schema_migrations::SCHEMA_MIGRATION_VERSIONS;
 pub mod versions;
 mod migration_2024_12_05_001;
 mod migration_2024_12_10_001;
+mod migration_2026_10_18_001;
+mod migration_2026_10_18_002;
+mod migration_2026_10_18_003;
+mod migration_2026_10_18_004;
+mod migration_2026_10_18_005;
+mod migration_2026_10_18_006;
 
 pub fn perform_migration(
     deps: DepsMut,
@@ -36,6 +42,30 @@ fn all_migration_entries<'a>() -> Vec<MigrationEntry<'a>> {
             version: versions::V2024_12_10_001,
             execute_fn: &migration_2024_12_10_001::execute,
         },
+        MigrationEntry {
+            version: versions::V2026_10_18_001,
+            execute_fn: &migration_2026_10_18_001::execute,
+        },
+        MigrationEntry {
+            version: versions::V2026_10_18_002,
+            execute_fn: &migration_2026_10_18_002::execute,
+        },
+        MigrationEntry {
+            version: versions::V2026_10_18_003,
+            execute_fn: &migration_2026_10_18_003::execute,
+        },
+        MigrationEntry {
+            version: versions::V2026_10_18_004,
+            execute_fn: &migration_2026_10_18_004::execute,
+        },
+        MigrationEntry {
+            version: versions::V2026_10_18_005,
+            execute_fn: &migration_2026_10_18_005::execute,
+        },
+        MigrationEntry {
+            version: versions::V2026_10_18_006,
+            execute_fn: &migration_2026_10_18_006::execute,
+        },
     ])
 }
 
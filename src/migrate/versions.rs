@@ -1,3 +1,9 @@
 
 pub static V2024_12_05_001: &'static str = "2024_12_05_001";
 pub static V2024_12_10_001: &'static str = "2024_12_10_001";
+pub static V2026_10_18_001: &'static str = "2026_10_18_001";
+pub static V2026_10_18_002: &'static str = "2026_10_18_002";
+pub static V2026_10_18_003: &'static str = "2026_10_18_003";
+pub static V2026_10_18_004: &'static str = "2026_10_18_004";
+pub static V2026_10_18_005: &'static str = "2026_10_18_005";
+pub static V2026_10_18_006: &'static str = "2026_10_18_006";
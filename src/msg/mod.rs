@@ -1,6 +1,13 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use secret_toolkit::permit::Permit;
+use crate::state::{CounterPolicy, Int64, PermitPolicy, RateLimitPolicy};
+use crate::state::roles::Role;
+use crate::state::multisig::PrivilegedAction;
+use crate::state::access_control::{AccessList, AccessMode};
+use crate::state::announcements::AnnouncementSeverity;
+use crate::state::read_grants::ReadScope;
+use crate::state::counters::CounterTarget;
 
 mod response;
 pub use response::*;
@@ -14,11 +21,130 @@ pub struct InstantiateMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
+    /// `counter_id` defaults to the default counter, same for other messages and queries accepting it
+    /// `target` defaults to shared counters, `counter_id` cannot be used with personal target
     Increment {
         count: Option<i32>,
         mark_history_as_public: Option<bool>,
+        counter_id: Option<String>,
+        target: Option<CounterTarget>,
+    },
+    /// `count` is a string in JSON like counts in responses, see `Int64`
+    Reset {
+        count: Int64,
+        counter_id: Option<String>,
+    },
+    /// Manual correction by managers, recorded in history as an adjustment
+    AdjustCount {
+        count_change: i32,
+        counter_id: Option<String>,
+    },
+    /// `manager` can reset and adjust this counter without having the capability
+    /// Counter ID returned as response data
+    CreateCounter {
+        name: String,
+        count: Int64,
+        manager: Option<String>,
+    },
+
+    /// Replaces the whole policy
+    UpdateConfig {
+        counter_policy: CounterPolicy,
+    },
+    /// Replaces the whole policy
+    UpdateRateLimitPolicy {
+        rate_limit_policy: RateLimitPolicy,
+    },
+    /// Replaces the whole policy
+    UpdatePermitPolicy {
+        permit_policy: PermitPolicy,
+    },
+    /// Increments larger than this (in absolute value) become pending requests
+    /// `None` disables approval
+    SetCountChangeApprovalThreshold {
+        threshold: Option<u32>,
+    },
+    ApproveCountChangeRequest {
+        request_id: String,
+    },
+    RejectCountChangeRequest {
+        request_id: String,
+    },
+
+    /// Propose a new contract manager, which has to accept before taking over
+    ProposeContractManager {
+        address: String,
+    },
+    AcceptContractManager {},
+    CancelManagerProposal {},
+
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+
+    /// Enable M-of-N approvals for privileged messages, empty `approvers` disables it
+    /// Once enabled, changing approvers requires a proposal too
+    SetApproverSet {
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl_in_seconds: Option<u64>,
+    },
+    /// Proposer approves automatically
+    ProposePrivilegedAction {
+        action: PrivilegedAction,
+    },
+    /// Executed once threshold is met
+    ApproveProposal {
+        proposal_id: String,
+    },
+
+    /// Delay count and config changes, zero disables it
+    /// Once enabled, changing delay is delayed too
+    SetTimelockDelay {
+        delay_in_seconds: u64,
+    },
+    /// Needs a proposal when multisig enabled
+    CancelQueuedAction {
+        queued_action_id: String,
+    },
+    /// Anyone can execute once the delay passed
+    ExecuteQueuedAction {
+        queued_action_id: String,
+    },
+
+    /// Switches not provided are left unchanged
+    SetPauseState {
+        increment: Option<bool>,
+        reset: Option<bool>,
+        bookmarks: Option<bool>,
+        public_queries: Option<bool>,
+    },
+
+    SetAccessMode {
+        mode: AccessMode,
+    },
+    AddToAccessList {
+        list: AccessList,
+        addresses: Vec<String>,
+    },
+    RemoveFromAccessList {
+        list: AccessList,
+        addresses: Vec<String>,
+    },
+    /// `max_uses` only applies to codes issued by managers, codes issued by users are single use
+    CreateInviteCode {
+        code: String,
+        max_uses: Option<u32>,
+    },
+    /// Join allowlist with an invite code
+    RedeemInviteCode {
+        code: String,
     },
-    Reset { count: i32 },
 
     AddBookmarkNumber {
         number: i32,
@@ -30,6 +156,75 @@ pub enum ExecuteMsg {
         memo_text: String,
         mark_entry_as_public: bool,
     },
+    /// Take down a public entry, owner still sees it
+    HideBookmarkedNumberEntry {
+        entry_id: String,
+        reason: String,
+    },
+    RestoreBookmarkedNumberEntry {
+        entry_id: String,
+    },
+
+    /// Times are unix time in milliseconds, missing means no bound
+    PostAnnouncement {
+        title: String,
+        body: String,
+        severity: AnnouncementSeverity,
+        visible_from_in_ms: Option<u64>,
+        visible_until_in_ms: Option<u64>,
+    },
+    /// Replaces all content
+    EditAnnouncement {
+        announcement_id: String,
+        title: String,
+        body: String,
+        severity: AnnouncementSeverity,
+        visible_from_in_ms: Option<u64>,
+        visible_until_in_ms: Option<u64>,
+    },
+    ExpireAnnouncement {
+        announcement_id: String,
+    },
+    DeleteAnnouncement {
+        announcement_id: String,
+    },
+
+    /// Generates a random viewing key, returned in response data
+    CreateViewingKey {
+        entropy: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+    SetViewingKey {
+        key: String,
+        /// optional message length padding
+        padding: Option<String>,
+    },
+
+    /// Let another address read own data of given scopes, replaces existing grant to the same address
+    /// Expiry is unix time in milliseconds, missing means never expires
+    GrantReadAccess {
+        grantee: String,
+        scopes: Vec<ReadScope>,
+        expires_at_in_ms: Option<u64>,
+    },
+    RevokeReadAccess {
+        grantee: String,
+    },
+
+    /// Let another contract query own data of given scopes, see `WithContractAuthorization`
+    /// The contract receives the key via `ContractAuthorizationCallbackMsg`, replacing the previous one
+    /// Access is granted by the key alone, like a viewing key, anyone holding it can query
+    /// `contract_address` and `code_hash` are where the key is sent, queries have no sender to check them against
+    AuthorizeContract {
+        contract_address: String,
+        code_hash: String,
+        scopes: Vec<ReadScope>,
+        expires_at_in_ms: Option<u64>,
+    },
+    RevokeContractAuthorization {
+        contract_address: String,
+    },
 
     /// disallow the use of a permit
     RevokePermit {
@@ -38,22 +233,57 @@ pub enum ExecuteMsg {
         /// optional message length padding
         padding: Option<String>,
     },
+    /// disallow the use of all permits signed so far
+    /// new permits must be signed with `PermitEpoch` of `permit_epoch` from `GetPermitEpoch`
+    RevokeAllPermits {
+        /// optional message length padding
+        padding: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    // GetCount returns the current count as a json-encoded number
-    GetCount {},
+    // GetCount returns the current count as a json-encoded string, see `Int64`
+    GetCount {
+        counter_id: Option<String>,
+    },
+    /// Count as of the end of the block at given time, errors for times before the counter was created or when count is unknown
+    /// Counts before the first recorded reset are unknown when earlier resets were not recorded
+    GetCountAt {
+        time_in_ms: u64,
+        counter_id: Option<String>,
+    },
     GetPrivileges {
         wallet_address: String,
     },
+    GetPauseState {},
+    GetConfig {},
+    GetPermitEpoch {
+        address: String,
+    },
     WithPermit {
-        permit: Permit,
+        permit: Permit<ContractPermission>,
+        query: QueryWithPermit,
+    },
+    /// For those who cannot sign permits, see `CreateViewingKey`
+    WithViewingKey {
+        address: String,
+        key: String,
+        query: QueryWithPermit,
+    },
+    /// For keys sent to contracts authorized by `owner`, see `AuthorizeContract`
+    /// Bearer key, `contract_address` only looks up the authorization and is not authenticated
+    /// Only queries of own data without `owner` param are allowed
+    WithContractAuthorization {
+        owner: String,
+        contract_address: String,
+        key: String,
         query: QueryWithPermit,
     },
 
     GlobalPublicUserCountUpdateHistoryEntries {
+        counter_id: Option<String>,
         page: Option<u32>,
         page_size: Option<u32>,
         reverse_order: Option<bool>,
@@ -64,27 +294,89 @@ pub enum QueryMsg {
         page_size: Option<u32>,
         reverse_order: Option<bool>,
     },
+    /// Only those visible at current block time
+    Announcements {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+
+    /// Pending timelocked actions, public so users get notice
+    QueuedActions {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+}
+
+/// Sent to contracts authorized with `AuthorizeContract`, they should store the key for querying
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractAuthorizationCallbackMsg {
+    ReceiveContractAuthorization {
+        owner: String,
+        key: String,
+        scopes: Vec<ReadScope>,
+        expires_at_in_ms: Option<u64>,
+    },
+}
+
+/// Scopes a permit can be signed with, each `QueryWithPermit` requires one of them
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractPermission {
+    Statistics,
+    History,
+    Bookmarks,
+    Admin,
+    /// Grants all scopes, for permits signed before scopes were introduced
+    Owner,
+
+    /// Not scopes, optional unless the contract has a max permit lifetime
+    /// Unix time in milliseconds, permit is rejected before this
+    IssuedAtInMs(u64),
+    /// Unix time in milliseconds, permit is rejected from this
+    ExpiresAtInMs(u64),
+    /// Required once `RevokeAllPermits` was used, see `GetPermitEpoch`
+    PermitEpoch(u32),
+
+    /// Entry ID, lets any bearer of the permit read that one entry only (see `SharedBookmarkedNumberEntry`)
+    /// Revoke with `RevokePermit` like any other permit
+    ShareBookmarkedNumberEntry(String),
 }
 
 /// queries using permits instead of viewing keys
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryWithPermit {
-    UserStatisticData {},
-    GlobalStatisticData {},
+    /// Count of viewer's personal counter, zero before first increment
+    /// Counter ID returned can be used for history and statistic queries
+    PersonalCount {},
+    /// `owner` defaults to the viewer, other owners require a read grant (see `GrantReadAccess`)
+    UserStatisticData {
+        owner: Option<String>,
+        counter_id: Option<String>,
+    },
+    GlobalStatisticData {
+        counter_id: Option<String>,
+    },
 
     UserCountUpdateHistoryEntries {
+        owner: Option<String>,
+        counter_id: Option<String>,
         page: Option<u32>,
         page_size: Option<u32>,
         reverse_order: Option<bool>,
     },
     GlobalUserCountUpdateHistoryEntries {
+        counter_id: Option<String>,
         page: Option<u32>,
         page_size: Option<u32>,
         reverse_order: Option<bool>,
     },
 
     OwnedBookmarkedNumberEntries {
+        owner: Option<String>,
         page: Option<u32>,
         page_size: Option<u32>,
         reverse_order: Option<bool>,
@@ -95,8 +387,53 @@ pub enum QueryWithPermit {
         reverse_order: Option<bool>,
     },
     OneOwnedBookmarkedNumberEntry {
+        owner: Option<String>,
+        entry_id: String,
+    },
+    /// Entry of permit signer, permit needs to be signed with `ShareBookmarkedNumberEntry` of the same entry
+    /// Rejected while the entry is hidden by moderators
+    SharedBookmarkedNumberEntry {
         entry_id: String,
     },
+
+    AccessListEntries {
+        list: AccessList,
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+
+    AuditLogEntries {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+
+    /// Own revoked permits, requires `owner` permission
+    RevokedPermits {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+
+    PendingProposals {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+
+    /// Own increments waiting for approval
+    PendingCountChangeRequests {
+        owner: Option<String>,
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
+    GlobalPendingCountChangeRequests {
+        page: Option<u32>,
+        page_size: Option<u32>,
+        reverse_order: Option<bool>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
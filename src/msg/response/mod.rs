@@ -1,22 +1,45 @@
-use cosmwasm_std::{Addr};
+use cosmwasm_std::{Addr, Uint64};
+use crate::state::{CounterPolicy, Int64, PermitPolicy, RateLimitPolicy};
+use crate::state::roles::{Capability, Role};
+use crate::state::audit_log::{AuditAction};
+use crate::state::multisig::{PrivilegedAction};
+use crate::state::announcements::{AnnouncementSeverity};
+use crate::state::user_count_update_history::{UserCountUpdateHistoryEntryKind};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+// Set as response data for messages returning something
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteAnswer {
+    CreateViewingKey {
+        key: String,
+    },
+    CreateCounter {
+        counter_id: String,
+    },
+}
+
 // We define a custom struct for each query response
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct CountResponse {
-    pub count: i32,
+    // Counts and tallies are strings in JSON, see `Int64`
+    pub count: Int64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryAnswer {
+    PersonalCount {
+        counter_id: String,
+        count: Int64,
+    },
     UserStatisticData {
-        count_increment_count: u32,
+        count_increment_count: Uint64,
     },
     GlobalStatisticData {
-        count_increment_count: i32,
-        count_reset_count: i32,
+        count_increment_count: Uint64,
+        count_reset_count: Uint64,
     },
 
     UserCountUpdateHistoryEntries {
@@ -31,18 +54,83 @@ pub enum QueryAnswer {
     OneBookmarkedNumberEntry {
         entry: BookmarkedNumberEntryInResponse,
     },
+
+    AccessListEntries {
+        addresses: Vec<Addr>,
+        total_count: u32,
+    },
+
+    AuditLogEntries {
+        entries: Vec<AuditLogEntryInResponse>,
+        total_count: u32,
+    },
+
+    PendingProposals {
+        proposals: Vec<ProposalInResponse>,
+        total_count: u32,
+    },
+
+    QueuedActions {
+        queued_actions: Vec<QueuedActionInResponse>,
+        total_count: u32,
+    },
+
+    PendingCountChangeRequests {
+        requests: Vec<CountChangeRequestInResponse>,
+        total_count: u32,
+    },
+
+    Announcements {
+        announcements: Vec<AnnouncementInResponse>,
+        total_count: u32,
+    },
+
+    RevokedPermits {
+        permits: Vec<RevokedPermitInResponse>,
+        total_count: u32,
+        permit_epoch: u32,
+    },
 }
 
 // We define a custom struct for each query response
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct PrivilegesResponse {
     pub is_contract_manager: bool,
+    pub is_pending_contract_manager: bool,
+    pub roles: Vec<Role>,
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct PauseStateResponse {
+    pub increment_paused: bool,
+    pub reset_paused: bool,
+    pub bookmarks_paused: bool,
+    pub public_queries_paused: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct PermitEpochResponse {
+    // Permits must be signed with `PermitEpoch` of this, unless it is 0
+    pub permit_epoch: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub counter_policy: CounterPolicy,
+    pub rate_limit_policy: RateLimitPolicy,
+    pub permit_policy: PermitPolicy,
+    pub count_change_approval_threshold: Option<u32>,
+    pub timelock_delay_in_seconds: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct UserCountUpdateHistoryEntryInResponse {
     pub user_addr: Addr,
-    pub count_change: i32,
+    pub count_change: Int64,
+    // `None` if unknown, only for some entries added before resets were recorded
+    pub resulting_count: Option<Int64>,
+    pub kind: UserCountUpdateHistoryEntryKind,
     // Using milliseconds since JS `Date` uses it
     pub created_at_in_ms: u64,
 }
@@ -60,4 +148,74 @@ pub struct BookmarkedNumberEntryInResponse {
     // Using milliseconds since JS `Date` uses it
     pub created_at_in_ms: u64,
     pub updated_at_in_ms: u64,
+
+    // Only present when hidden by moderators, hidden entries are only visible to owner and managers
+    pub hidden_at_in_ms: Option<u64>,
+    pub hidden_reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct AuditLogEntryInResponse {
+    pub actor_addr: Addr,
+    pub action: AuditAction,
+    pub target: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    // Using milliseconds since JS `Date` uses it
+    pub created_at_in_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ProposalInResponse {
+    pub proposal_id: String,
+    pub proposer_addr: Addr,
+    pub action: PrivilegedAction,
+    pub approvals: Vec<Addr>,
+    // Expired proposals are kept but can no longer be approved
+    pub is_expired: bool,
+    // Using milliseconds since JS `Date` uses it
+    pub created_at_in_ms: u64,
+    pub expires_at_in_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct QueuedActionInResponse {
+    pub queued_action_id: String,
+    pub queued_by: Addr,
+    pub action: PrivilegedAction,
+    pub is_executable: bool,
+    // Using milliseconds since JS `Date` uses it
+    pub queued_at_in_ms: u64,
+    pub execute_after_in_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CountChangeRequestInResponse {
+    pub request_id: String,
+    pub user_addr: Addr,
+    pub count_change: i32,
+    pub mark_history_as_public: bool,
+    pub counter_id: String,
+    // Using milliseconds since JS `Date` uses it
+    pub created_at_in_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct AnnouncementInResponse {
+    pub announcement_id: String,
+    pub title: String,
+    pub body: String,
+    pub severity: AnnouncementSeverity,
+    // Using milliseconds since JS `Date` uses it
+    pub visible_from_in_ms: Option<u64>,
+    pub visible_until_in_ms: Option<u64>,
+    pub created_at_in_ms: u64,
+    pub updated_at_in_ms: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct RevokedPermitInResponse {
+    pub permit_name: String,
+    // Using milliseconds since JS `Date` uses it
+    pub revoked_at_in_ms: u64,
 }
@@ -0,0 +1,16 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::msg::{ConfigResponse};
+use crate::state::{CONFIG, RATE_LIMIT_POLICY, COUNT_CHANGE_APPROVAL_THRESHOLD, PERMIT_POLICY};
+use crate::state::timelock::{TimelockManager};
+
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    // Contract manager not exposed, use `GetPrivileges` to check
+    Ok(ConfigResponse {
+        counter_policy: config.counter_policy,
+        rate_limit_policy: RATE_LIMIT_POLICY.may_load(deps.storage)?.unwrap_or_default(),
+        permit_policy: PERMIT_POLICY.may_load(deps.storage)?.unwrap_or_default(),
+        count_change_approval_threshold: COUNT_CHANGE_APPROVAL_THRESHOLD.may_load(deps.storage)?,
+        timelock_delay_in_seconds: TimelockManager::get_delay_in_seconds(deps.storage)?,
+    })
+}
@@ -1,8 +1,74 @@
-use cosmwasm_std::{Deps, StdResult};
+use cosmwasm_std::{Deps, StdError, StdResult, Timestamp};
 use crate::msg::CountResponse;
-use crate::state::STATE;
+use crate::state::counters::{CountersManager};
+use crate::state::count_checkpoints::{CountCheckpointsManager};
 
-pub fn query_count(deps: Deps) -> StdResult<CountResponse> {
-    let state = STATE.load(deps.storage)?;
+pub fn query_count(deps: Deps, counter_id: Option<String>) -> StdResult<CountResponse> {
+    let counter_id = resolve_public_counter_id(counter_id)?;
+    let state = CountersManager::get_state(deps.storage, &counter_id)?;
     Ok(CountResponse { count: state.count })
-}
\ No newline at end of file
+}
+
+pub fn query_count_at(deps: Deps, time_in_ms: u64, counter_id: Option<String>) -> StdResult<CountResponse> {
+    let counter_id = resolve_public_counter_id(counter_id)?;
+    let time = Timestamp::from_nanos(time_in_ms.saturating_mul(1_000_000));
+    let count = CountCheckpointsManager::get_count_at(deps.storage, &counter_id, time, None)?.
+        ok_or_else(|| StdError::generic_err("Count unknown at given time"))?;
+    Ok(CountResponse { count })
+}
+
+// Personal counters are private, see `PersonalCount`
+pub(super) fn resolve_public_counter_id(counter_id: Option<String>) -> StdResult<String> {
+    let counter_id = CountersManager::resolve_counter_id(counter_id);
+    if CountersManager::is_personal_counter_id(&counter_id) {
+        return Err(StdError::generic_err("Counter not found"));
+    }
+
+    Ok(counter_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::Addr;
+    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::state::Int64;
+    use crate::state::counters::CounterTarget;
+
+    #[test]
+    fn query_count_at_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let instantiated_at = env.block.time;
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), init_msg)?;
+
+        env.block.time = instantiated_at.plus_seconds(10);
+        let increment_msg = ExecuteMsg::Increment { count: Some(3), mark_history_as_public: None, counter_id: None, target: None };
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), env.clone(), mock_info("user", &[]), increment_msg)?;
+
+        let time_in_ms = |time: Timestamp| time.nanos() / 1_000_000;
+        assert!(query_count_at(deps.as_ref(), time_in_ms(instantiated_at.minus_seconds(1)), None).is_err());
+        assert_eq!(query_count_at(deps.as_ref(), time_in_ms(instantiated_at.plus_seconds(9)), None)?.count, Int64::new(17));
+        assert_eq!(query_count_at(deps.as_ref(), time_in_ms(instantiated_at.plus_seconds(10)), None)?.count, Int64::new(20));
+
+        Ok(())
+    }
+
+    #[test]
+    fn personal_counters_not_publicly_queryable() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), mock_env(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: Some(3), mark_history_as_public: Some(true), counter_id: None, target: Some(CounterTarget::Personal) };
+        let _res = crate::execute::execute_dispatch(deps.as_mut(), mock_env(), mock_info("user", &[]), increment_msg)?;
+
+        let personal_counter_id = Some(CountersManager::personal_counter_id(&Addr::unchecked("user")));
+        assert!(query_count(deps.as_ref(), personal_counter_id.clone()).is_err());
+        let history_msg = crate::msg::QueryMsg::GlobalPublicUserCountUpdateHistoryEntries { counter_id: personal_counter_id, page: None, page_size: None, reverse_order: None };
+        assert!(crate::query::query_dispatch(deps.as_ref(), mock_env(), history_msg).is_err());
+
+        Ok(())
+    }
+}
@@ -1,26 +1,40 @@
 use cosmwasm_std::{Deps, Env, StdResult, Binary, to_binary};
 use secret_toolkit::utils::{pad_query_result};
 use crate::msg::QueryMsg;
-use crate::state::BLOCK_SIZE;
+use crate::state::{BLOCK_SIZE, PauseState, PausableOperation};
 
 mod count;
 mod with_permit;
 mod privileges;
+mod pause_state;
+mod config;
+mod permit_epoch;
 mod global_public_user_count_update_history_entries;
 mod public;
+mod queued_actions;
+
+pub fn query_dispatch(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    ensure_msg_not_paused(deps, &msg)?;
 
-pub fn query_dispatch(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let res = match msg {
-        QueryMsg::GetCount {} => to_binary(&count::query_count(deps)?),
+        QueryMsg::GetCount { counter_id } => to_binary(&count::query_count(deps, counter_id)?),
+        QueryMsg::GetCountAt { time_in_ms, counter_id } => to_binary(&count::query_count_at(deps, time_in_ms, counter_id)?),
         QueryMsg::GetPrivileges { wallet_address } => to_binary(&privileges::query_privileges(deps, wallet_address)?),
-        QueryMsg::WithPermit { permit, query } => with_permit::permit_query_dispatch(deps, permit, query),
+        QueryMsg::GetPauseState {} => to_binary(&pause_state::query_pause_state(deps)?),
+        QueryMsg::GetConfig {} => to_binary(&config::query_config(deps)?),
+        QueryMsg::GetPermitEpoch { address } => to_binary(&permit_epoch::query_permit_epoch(deps, address)?),
+        QueryMsg::WithPermit { permit, query } => with_permit::permit_query_dispatch(deps, &env, permit, query),
+        QueryMsg::WithViewingKey { address, key, query } => with_permit::viewing_key_query_dispatch(deps, &env, address, key, query),
+        QueryMsg::WithContractAuthorization { owner, contract_address, key, query } => {
+            with_permit::contract_authorization_query_dispatch(deps, &env, owner, contract_address, key, query)
+        },
 
-        QueryMsg::GlobalPublicUserCountUpdateHistoryEntries {page, page_size, reverse_order} => {
+        QueryMsg::GlobalPublicUserCountUpdateHistoryEntries {counter_id, page, page_size, reverse_order} => {
             let page_w_fallback = page.unwrap_or(1);
             let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
             let page_size_w_fallback = page_size.unwrap_or(10);
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
-            to_binary(&global_public_user_count_update_history_entries::query_user_count_update_history_entries(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?)
+            to_binary(&global_public_user_count_update_history_entries::query_user_count_update_history_entries(deps, &count::resolve_public_counter_id(counter_id)?, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?)
         },
 
         QueryMsg::GlobalPublicBookmarkedNumberEntries {page, page_size, reverse_order} => {
@@ -30,7 +44,35 @@ pub fn query_dispatch(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary>
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
             to_binary(&public::bookmarked_number_entries::global_public_entries::query_entries(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?)
         },
+        // Not affected by pausing public queries, could be used to announce the pause
+        QueryMsg::Announcements {page, page_size, reverse_order} => {
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            to_binary(&public::announcements::query_announcements(deps, &env, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?)
+        },
+
+        QueryMsg::QueuedActions {page, page_size, reverse_order} => {
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            to_binary(&queued_actions::query_queued_actions(deps, &env, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?)
+        },
     };
 
     pad_query_result(res, BLOCK_SIZE)
 }
+
+fn ensure_msg_not_paused(deps: Deps, msg: &QueryMsg) -> StdResult<()> {
+    match msg {
+        QueryMsg::GetCount { .. } |
+        QueryMsg::GetCountAt { .. } |
+        QueryMsg::GlobalPublicUserCountUpdateHistoryEntries { .. } |
+        QueryMsg::GlobalPublicBookmarkedNumberEntries { .. } => {
+            PauseState::ensure_not_paused(deps.storage, PausableOperation::PublicQueries)
+        },
+        _ => Ok(()),
+    }
+}
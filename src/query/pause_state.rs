@@ -0,0 +1,13 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::msg::PauseStateResponse;
+use crate::state::PAUSE_STATE;
+
+pub fn query_pause_state(deps: Deps) -> StdResult<PauseStateResponse> {
+    let pause_state = PAUSE_STATE.may_load(deps.storage)?.unwrap_or_default();
+    Ok(PauseStateResponse {
+        increment_paused: pause_state.increment_paused,
+        reset_paused: pause_state.reset_paused,
+        bookmarks_paused: pause_state.bookmarks_paused,
+        public_queries_paused: pause_state.public_queries_paused,
+    })
+}
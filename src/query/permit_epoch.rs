@@ -0,0 +1,11 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::msg::PermitEpochResponse;
+use crate::state::permits::{PermitsManager};
+
+/// Public since permits for the current epoch have to be signed before any permit query works
+pub fn query_permit_epoch(deps: Deps, address: String) -> StdResult<PermitEpochResponse> {
+    let addr = deps.api.addr_validate(&address)?;
+    Ok(PermitEpochResponse {
+        permit_epoch: PermitsManager::get_epoch(deps.storage, &addr),
+    })
+}
@@ -1,13 +1,18 @@
 use cosmwasm_std::{Deps, StdError, StdResult};
 use crate::msg::PrivilegesResponse;
-use crate::state::CONFIG;
+use crate::state::{CONFIG, PENDING_CONTRACT_MANAGER};
+use crate::state::roles::RolesManager;
 
 pub fn query_privileges(deps: Deps, wallet_address: String) -> StdResult<PrivilegesResponse> {
     match deps.api.addr_validate(&wallet_address) {
         Ok(addr) => {
             let config = CONFIG.load(deps.storage)?;
+            let pending_contract_manager = PENDING_CONTRACT_MANAGER.may_load(deps.storage)?;
             Ok(PrivilegesResponse {
                 is_contract_manager: addr == config.contract_manager,
+                is_pending_contract_manager: pending_contract_manager.is_some_and(|pending_addr| pending_addr == addr),
+                roles: RolesManager::get_roles(deps.storage, &addr),
+                capabilities: RolesManager::get_capabilities(deps.storage, &addr)?,
             })
         }
         Err(_) => Err(StdError::generic_err("Invalid address")),
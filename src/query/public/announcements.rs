@@ -0,0 +1,72 @@
+use cosmwasm_std::{Deps, Env, StdResult};
+use crate::msg::{QueryAnswer, AnnouncementInResponse};
+use crate::state::announcements::{AnnouncementsManager};
+
+pub fn query_announcements(deps: Deps, env: &Env, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let (entries, total_count) = AnnouncementsManager::get_visible_entries(
+        deps.storage,
+        env,
+        page_one_based - 1,
+        page_size,
+        reverse_order,
+        suffix_4_test,
+    )?;
+    let response_entries = entries.into_iter().map({|(announcement_id, a)| AnnouncementInResponse{
+        announcement_id,
+        title: a.title,
+        body: a.body,
+        severity: a.severity,
+        visible_from_in_ms: a.visible_from.map(|t| t.nanos() / 1_000_000),
+        visible_until_in_ms: a.visible_until.map(|t| t.nanos() / 1_000_000),
+        created_at_in_ms: a.created_at.nanos() / 1_000_000,
+        updated_at_in_ms: a.updated_at.nanos() / 1_000_000,
+    }}).collect();
+    Ok(QueryAnswer::Announcements {
+        announcements: response_entries,
+        total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use crate::state::announcements::{Announcement, AnnouncementSeverity};
+    use nanoid::nanoid;
+
+    #[test]
+    fn query_announcements_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+
+        let announcement_id = AnnouncementsManager::add_entry(deps.as_mut().storage, &env, Announcement {
+            title: "Title".to_string(),
+            body: "Body".to_string(),
+            severity: AnnouncementSeverity::Info,
+            visible_from: None,
+            visible_until: None,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }, Some(suffix_4_test))?;
+
+        assert_eq!(query_announcements(deps.as_ref(), &env, 1, 10, false, Some(suffix_4_test))?, QueryAnswer::Announcements {
+            announcements: vec![
+                AnnouncementInResponse{
+                    announcement_id,
+                    title: "Title".to_string(),
+                    body: "Body".to_string(),
+                    severity: AnnouncementSeverity::Info,
+                    visible_from_in_ms: None,
+                    visible_until_in_ms: None,
+                    created_at_in_ms: Default::default(),
+                    updated_at_in_ms: Default::default(),
+                },
+            ],
+            total_count: 1,
+        });
+
+        Ok(())
+    }
+}
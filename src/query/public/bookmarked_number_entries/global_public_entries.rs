@@ -23,6 +23,10 @@ pub fn query_entries(deps: Deps, page_one_based: u32, page_size: u32, reverse_or
         },
         created_at_in_ms: t.1.created_at.nanos() / 1_000_000,
         updated_at_in_ms: t.1.updated_at.nanos() / 1_000_000,
+
+        // Hidden entries are excluded from public index
+        hidden_at_in_ms: None,
+        hidden_reason: None,
     }}).collect();
     let total_count = BookmarkedNumbersManager::get_public_entries_total_count(deps.storage, suffix_4_test)?;
     Ok(QueryAnswer::BookmarkedNumberEntries {
@@ -116,6 +120,9 @@ mod tests {
 
                         created_at_in_ms: Default::default(),
                         updated_at_in_ms: Default::default(),
+
+                        hidden_at_in_ms: None,
+                        hidden_reason: None,
                     },
                     BookmarkedNumberEntryInResponse{
                         entry_id: get_generated_ulid(3, &env)?,
@@ -128,6 +135,9 @@ mod tests {
 
                         created_at_in_ms: Default::default(),
                         updated_at_in_ms: Default::default(),
+
+                        hidden_at_in_ms: None,
+                        hidden_reason: None,
                     },
                     BookmarkedNumberEntryInResponse{
                         entry_id: get_generated_ulid(4, &env)?,
@@ -140,6 +150,9 @@ mod tests {
 
                         created_at_in_ms: Default::default(),
                         updated_at_in_ms: Default::default(),
+
+                        hidden_at_in_ms: None,
+                        hidden_reason: None,
                     },
                 ],
                 total_count: 4,
@@ -158,6 +171,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
                 BookmarkedNumberEntryInResponse{
                     entry_id: get_generated_ulid(4, &env)?,
@@ -170,6 +186,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
                 BookmarkedNumberEntryInResponse{
                     entry_id: get_generated_ulid(3, &env)?,
@@ -182,6 +201,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
             ],
             total_count: 4,
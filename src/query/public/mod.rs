@@ -1 +1,2 @@
 pub mod bookmarked_number_entries;
+pub mod announcements;
@@ -0,0 +1,62 @@
+use cosmwasm_std::{Deps, Env, StdResult};
+use crate::msg::{QueryAnswer, QueuedActionInResponse};
+use crate::state::timelock::{TimelockManager};
+
+pub fn query_queued_actions(deps: Deps, env: &Env, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let queued_actions = TimelockManager::get_queued_actions(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
+    let response_queued_actions = queued_actions.iter().map({|(queued_action_id, a)| QueuedActionInResponse{
+        queued_action_id: queued_action_id.clone(),
+        queued_by: a.queued_by.clone(),
+        action: a.action.clone(),
+        is_executable: a.is_executable(env),
+        queued_at_in_ms: a.queued_at.nanos() / 1_000_000,
+        execute_after_in_ms: a.execute_after.nanos() / 1_000_000,
+    }}).collect();
+    let total_count = TimelockManager::get_queued_actions_total_count(deps.storage, suffix_4_test)?;
+    Ok(QueryAnswer::QueuedActions {
+        queued_actions: response_queued_actions,
+        total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Addr};
+    use crate::state::Int64;
+    use crate::state::multisig::{PrivilegedAction};
+    use crate::state::timelock::{QueuedAction};
+    use nanoid::nanoid;
+
+    #[test]
+    fn query_queued_actions_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+
+        let queued_action_id = TimelockManager::add_queued_action(deps.as_mut().storage, &env, QueuedAction {
+            queued_by: Addr::unchecked("owner"),
+            action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+            queued_at: Default::default(),
+            execute_after: Default::default(),
+        }, Some(suffix_4_test))?;
+
+        assert_eq!(query_queued_actions(deps.as_ref(), &env, 1, 10, false, Some(suffix_4_test))?, QueryAnswer::QueuedActions {
+            queued_actions: vec![
+                QueuedActionInResponse{
+                    queued_action_id,
+                    queued_by: Addr::unchecked("owner"),
+                    action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+                    is_executable: true,
+                    queued_at_in_ms: Default::default(),
+                    execute_after_in_ms: Default::default(),
+                },
+            ],
+            total_count: 1,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::msg::{QueryAnswer};
+use crate::state::access_control::{AccessControlManager, AccessList};
+
+pub fn query_access_list_entries(deps: Deps, list: AccessList, page_one_based: u32, page_size: u32, reverse_order: bool) -> StdResult<QueryAnswer> {
+    let addresses = AccessControlManager::get_list_entries(deps.storage, list, page_one_based - 1, page_size, reverse_order)?;
+    let total_count = AccessControlManager::get_list_entries_total_count(deps.storage, list)?;
+    Ok(QueryAnswer::AccessListEntries {
+        addresses,
+        total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Addr};
+
+    #[test]
+    fn query_access_list_entries_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let addrs = vec![
+            Addr::unchecked("addr1"),
+            Addr::unchecked("addr2"),
+            Addr::unchecked("addr3"),
+        ];
+        AccessControlManager::add_to_list(deps.as_mut().storage, AccessList::Denylist, &addrs)?;
+
+        assert_eq!(query_access_list_entries(deps.as_ref(), AccessList::Denylist, 1, 2, false)?, QueryAnswer::AccessListEntries {
+            addresses: vec![Addr::unchecked("addr1"), Addr::unchecked("addr2")],
+            total_count: 3,
+        });
+        assert_eq!(query_access_list_entries(deps.as_ref(), AccessList::Denylist, 1, 2, true)?, QueryAnswer::AccessListEntries {
+            addresses: vec![Addr::unchecked("addr3"), Addr::unchecked("addr2")],
+            total_count: 3,
+        });
+        assert_eq!(query_access_list_entries(deps.as_ref(), AccessList::Allowlist, 1, 2, false)?, QueryAnswer::AccessListEntries {
+            addresses: vec![],
+            total_count: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::msg::{QueryAnswer, AuditLogEntryInResponse};
+use crate::state::audit_log::{AuditLogManager};
+
+pub fn query_audit_log_entries(deps: Deps, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let entries = AuditLogManager::get_entries(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
+    let response_entries = entries.iter().map({|e| AuditLogEntryInResponse{
+        actor_addr: e.actor_addr.clone(),
+        action: e.action,
+        target: e.target.clone(),
+        old_value: e.old_value.clone(),
+        new_value: e.new_value.clone(),
+        created_at_in_ms: e.created_at.nanos() / 1_000_000,
+    }}).collect();
+    let total_count = AuditLogManager::get_entries_total_count(deps.storage, suffix_4_test)?;
+    Ok(QueryAnswer::AuditLogEntries {
+        entries: response_entries,
+        total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Addr};
+    use crate::state::audit_log::{AuditAction, AuditLogEntry};
+    use nanoid::nanoid;
+
+    #[test]
+    fn query_audit_log_entries_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let actor_addr = "actor_addr";
+
+        let entries: Vec<AuditLogEntry> = vec![
+            AuditLogEntry{
+                actor_addr: Addr::unchecked(actor_addr),
+                action: AuditAction::ResetCount,
+                target: None,
+                old_value: Some("17".to_string()),
+                new_value: Some("0".to_string()),
+                created_at: Default::default(),
+            },
+            AuditLogEntry{
+                actor_addr: Addr::unchecked(actor_addr),
+                action: AuditAction::HideBookmarkedNumberEntry,
+                target: Some("entry_id".to_string()),
+                old_value: None,
+                new_value: Some("spam".to_string()),
+                created_at: Default::default(),
+            },
+        ];
+        entries.iter().for_each(|entry| {
+            // save
+            assert!(AuditLogManager::add_entry(deps.as_mut().storage, &env, entry.clone(), Some(suffix_4_test)).is_ok());
+        });
+        // actual query
+        assert_eq!(query_audit_log_entries(deps.as_ref(), 1, 1, true, Some(suffix_4_test))?, QueryAnswer::AuditLogEntries {
+            entries: vec![
+                AuditLogEntryInResponse{
+                    actor_addr: Addr::unchecked(actor_addr),
+                    action: AuditAction::HideBookmarkedNumberEntry,
+                    target: Some("entry_id".to_string()),
+                    old_value: None,
+                    new_value: Some("spam".to_string()),
+                    created_at_in_ms: Default::default(),
+                },
+            ],
+            total_count: 2,
+        });
+
+        Ok(())
+    }
+}
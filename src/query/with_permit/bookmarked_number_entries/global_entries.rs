@@ -4,19 +4,25 @@ use crate::state::bookmarked_numbers::{BookmarkedNumbersManager};
 
 pub fn query_entries(deps: Deps, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
     let entries = BookmarkedNumbersManager::get_global_entries(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
-    let response_entries = entries.iter().map({|t| BookmarkedNumberEntryInResponse{
-        entry_id: t.0.clone(),
-        owner_addr: t.1.owner_addr.clone(),
-        number: t.1.number,
-        memo_text: t.1.memo_text.clone(),
-        marked_as_public_at_in_ms: if t.1.marked_as_public_at.is_some() {
-            Some(t.1.marked_as_public_at.unwrap().nanos() / 1_000_000)
+    let response_entries = entries.iter().map({|t| {
+        let moderation_record = BookmarkedNumbersManager::get_moderation_record(deps.storage, &t.0, suffix_4_test);
+        BookmarkedNumberEntryInResponse{
+            entry_id: t.0.clone(),
+            owner_addr: t.1.owner_addr.clone(),
+            number: t.1.number,
+            memo_text: t.1.memo_text.clone(),
+            marked_as_public_at_in_ms: if t.1.marked_as_public_at.is_some() {
+                Some(t.1.marked_as_public_at.unwrap().nanos() / 1_000_000)
+            }
+            else {
+                None
+            },
+            created_at_in_ms: t.1.created_at.nanos() / 1_000_000,
+            updated_at_in_ms: t.1.updated_at.nanos() / 1_000_000,
+
+            hidden_at_in_ms: moderation_record.as_ref().map(|r| r.hidden_at.nanos() / 1_000_000),
+            hidden_reason: moderation_record.map(|r| r.hidden_reason),
         }
-        else {
-            None
-        },
-        created_at_in_ms: t.1.created_at.nanos() / 1_000_000,
-        updated_at_in_ms: t.1.updated_at.nanos() / 1_000_000,
     }}).collect();
     let total_count = BookmarkedNumbersManager::get_global_entries_total_count(deps.storage, suffix_4_test)?;
     Ok(QueryAnswer::BookmarkedNumberEntries {
@@ -82,6 +88,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
                 BookmarkedNumberEntryInResponse{
                     entry_id: get_generated_ulid(2, &env)?,
@@ -94,6 +103,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
             ],
             total_count: 2,
@@ -111,6 +123,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
                 BookmarkedNumberEntryInResponse{
                     entry_id: get_generated_ulid(1, &env)?,
@@ -123,6 +138,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
             ],
             total_count: 2,
@@ -10,6 +10,7 @@ pub fn query(deps: Deps, viewer: String, entry_id: String, suffix_4_test: Option
         entry_id.clone(),
         suffix_4_test,
     )?;
+    let moderation_record = BookmarkedNumbersManager::get_moderation_record(deps.storage, &entry_id, suffix_4_test);
     Ok(QueryAnswer::OneBookmarkedNumberEntry {
         entry: BookmarkedNumberEntryInResponse {
             entry_id,
@@ -24,6 +25,9 @@ pub fn query(deps: Deps, viewer: String, entry_id: String, suffix_4_test: Option
             },
             created_at_in_ms: entry.created_at.nanos() / 1_000_000,
             updated_at_in_ms: entry.updated_at.nanos() / 1_000_000,
+
+            hidden_at_in_ms: moderation_record.as_ref().map(|r| r.hidden_at.nanos() / 1_000_000),
+            hidden_reason: moderation_record.map(|r| r.hidden_reason),
         },
     })
 }
@@ -86,6 +90,9 @@ mod tests {
 
                     created_at_in_ms: Default::default(),
                     updated_at_in_ms: Default::default(),
+
+                    hidden_at_in_ms: None,
+                    hidden_reason: None,
                 },
             });
         });
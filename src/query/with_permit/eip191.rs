@@ -0,0 +1,147 @@
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{Deps, StdError, StdResult, to_binary};
+use secret_toolkit::permit::{pubkey_to_account, Permit, Permissions, RevokedPermits, SignedPermit};
+use sha3::{Digest, Keccak256};
+
+/// `pub_key.type` of permits signed with `personal_sign` of Ethereum wallets
+/// `pub_key.value` must still be the compressed public key (33 bytes), recovered from the signature by the client,
+/// so the permit maps to the same Secret address as when signed with a Cosmos wallet using the same key
+pub const EIP191_PUB_KEY_TYPE: &str = "eip191/PubKeySecp256k1";
+
+pub fn is_eip191_permit<Permission: Permissions>(permit: &Permit<Permission>) -> bool {
+    permit.signature.pub_key.r#type == EIP191_PUB_KEY_TYPE
+}
+
+/// Same as `secret_toolkit::permit::validate` but with the sign doc signed via EIP-191 `personal_sign`
+pub fn validate<Permission: Permissions>(
+    deps: Deps,
+    storage_prefix: &str,
+    permit: &Permit<Permission>,
+    current_token_address: String,
+) -> StdResult<String> {
+    if !permit.check_token(&current_token_address) {
+        return Err(StdError::generic_err(format!(
+            "Permit doesn't apply to token {:?}, allowed tokens: {:?}",
+            current_token_address,
+            permit.params.allowed_tokens,
+        )));
+    }
+
+    let pubkey = &permit.signature.pub_key.value;
+    if pubkey.len() != 33 {
+        return Err(StdError::generic_err("Permit public key must be a compressed secp256k1 public key"));
+    }
+
+    // Same bytes as signed by Cosmos wallets
+    let signed_bytes = to_binary(&SignedPermit::from_params(&permit.params))?;
+    // Trailing recovery id from Ethereum wallets not used
+    let signature = permit.signature.signature.as_slice();
+    let signature = match signature.len() {
+        64 => signature,
+        65 => &signature[..64],
+        _ => return Err(StdError::generic_err("Permit signature must be 64 or 65 bytes")),
+    };
+    let verified = deps.api.secp256k1_verify(&eip191_message_hash(signed_bytes.as_slice()), signature, pubkey.as_slice())
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    if !verified {
+        return Err(StdError::generic_err("Failed to verify signatures for the given permit"));
+    }
+
+    // Same as `secret_toolkit::permit::validate` without custom prefix
+    let account = bech32::encode("secret", pubkey_to_account(pubkey).to_base32(), Variant::Bech32)
+        .map_err(|err| StdError::generic_err(format!("Failed to encode account address: {}", err)))?;
+    if RevokedPermits::is_permit_revoked(deps.storage, storage_prefix, &account, &permit.params.permit_name) {
+        return Err(StdError::generic_err(format!(
+            "Permit {:?} was revoked by account {:?}",
+            permit.params.permit_name,
+            account,
+        )));
+    }
+
+    Ok(account)
+}
+
+fn eip191_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes());
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Binary;
+    use cosmwasm_std::testing::{mock_dependencies};
+    use secret_toolkit::permit::{PermitParams, PermitSignature, PubKey};
+    use crate::msg::ContractPermission;
+
+    #[test]
+    fn validate_works() -> StdResult<()> {
+        // Same as `hashMessage("hello world")` of ethers
+        assert_eq!(
+            Binary::from(eip191_message_hash(b"hello world").as_slice()).to_base64(),
+            "2euhbtDsrkMrcf4AjJjMhyu0zCFNMiCjbzZTJs+AfWg=",
+        );
+
+        let deps = mock_dependencies();
+        let permit = Permit {
+            params: PermitParams {
+                allowed_tokens: vec!["contract".to_string()],
+                permit_name: "test".to_string(),
+                chain_id: "secret-4".to_string(),
+                permissions: vec![ContractPermission::Owner],
+            },
+            signature: PermitSignature {
+                pub_key: PubKey {
+                    r#type: EIP191_PUB_KEY_TYPE.to_string(),
+                    value: Binary::from(vec![2u8; 33]),
+                },
+                signature: Binary::from(vec![1u8; 65]),
+            },
+        };
+        assert!(is_eip191_permit(&permit));
+        assert!(validate(deps.as_ref(), "revoked_permits", &permit, "other_contract".to_string()).is_err());
+        assert!(validate(deps.as_ref(), "revoked_permits", &permit, "contract".to_string()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_signed_permit_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        // `signMessage` of ethers with the first Hardhat test account (0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266),
+        // message being `SignedPermit` bytes of the params below
+        let signature_with_recovery_id = Binary::from_base64("AVHO1Shk6qAAi6AIQz+2mtUh6+KwW2pk6gjGj+njZxxhNCjHs+LVxsFXjXoKpYptmw2wYUzI5WZ/+KWOLPud7hw=")?;
+        let build_permit = |permit_name: &str, signature: &[u8]| Permit {
+            params: PermitParams {
+                allowed_tokens: vec!["contract".to_string()],
+                permit_name: permit_name.to_string(),
+                chain_id: "secret-4".to_string(),
+                permissions: vec![ContractPermission::Owner],
+            },
+            signature: PermitSignature {
+                pub_key: PubKey {
+                    r#type: EIP191_PUB_KEY_TYPE.to_string(),
+                    value: Binary::from_base64("A4MYU1tUEF1Keq5gwI/EX5aHGBtP38YlvRp1P6c5f+11").unwrap(),
+                },
+                signature: Binary::from(signature),
+            },
+        };
+        // Same address as when the key is used by Cosmos wallets
+        let account = "secret15428vq2uzwhm3taey9sr9x5vm6tk78ewm3pkn9".to_string();
+
+        let permit = build_permit("test", signature_with_recovery_id.as_slice());
+        assert_eq!(validate(deps.as_ref(), "revoked_permits", &permit, "contract".to_string())?, account);
+        // Without recovery ID
+        let permit_wo_recovery_id = build_permit("test", &signature_with_recovery_id.as_slice()[..64]);
+        assert_eq!(validate(deps.as_ref(), "revoked_permits", &permit_wo_recovery_id, "contract".to_string())?, account);
+        // Params not signed
+        assert!(validate(deps.as_ref(), "revoked_permits", &build_permit("other", signature_with_recovery_id.as_slice()), "contract".to_string()).is_err());
+
+        RevokedPermits::revoke_permit(deps.as_mut().storage, "revoked_permits", &account, "test");
+        assert!(validate(deps.as_ref(), "revoked_permits", &permit, "contract".to_string()).is_err());
+
+        Ok(())
+    }
+}
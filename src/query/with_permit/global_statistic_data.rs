@@ -1,11 +1,9 @@
 use cosmwasm_std::{Deps, StdResult};
-use secret_toolkit::storage::Item;
 use crate::msg::{QueryAnswer};
-use crate::state::{STATE, State};
+use crate::state::counters::{CountersManager};
 
-pub fn query_global_statistic_data(deps: Deps, _viewer: String, custom_store: Option<&Item<State>>) -> StdResult<QueryAnswer> {
-    let store = custom_store.unwrap_or_else(|| &STATE);
-    let state = store.load(deps.storage)?;
+pub fn query_global_statistic_data(deps: Deps, _viewer: String, counter_id: &str) -> StdResult<QueryAnswer> {
+    let state = CountersManager::get_state(deps.storage, counter_id)?;
     Ok(QueryAnswer::GlobalStatisticData {
         count_increment_count: state.count_increment_count,
         count_reset_count: state.count_reset_count,
@@ -16,30 +14,26 @@ pub fn query_global_statistic_data(deps: Deps, _viewer: String, custom_store: Op
 mod tests {
     use super::*;
     use cosmwasm_std::testing::*;
-    use rand::distributions::{Alphanumeric, DistString};
+    use cosmwasm_std::{Uint64};
+    use crate::state::{Int64, State};
+    use crate::state::counters::{DEFAULT_COUNTER_ID};
 
     #[test]
-    fn user_statistic_data_store_with_keymap_works() -> StdResult<()> {
+    fn query_global_statistic_data_works() -> StdResult<()> {
         let mut deps = mock_dependencies();
-        let random_code = Alphanumeric.sample_string(&mut rand::thread_rng(), 32);
-        let store = STATE.add_suffix(random_code.as_bytes());
 
-        // save + load
-        assert!(store.save(deps.as_mut().storage, &State {
-            count: 999,
-            count_increment_count: 2,
-            count_reset_count: 1,
-        }).is_ok());
-        assert_eq!(store.load(deps.as_ref().storage), Ok(State {
-            count: 999,
-            count_increment_count: 2,
-            count_reset_count: 1,
-        }));
+        CountersManager::add_default_counter(deps.as_mut().storage, &mock_env(), &State {
+            count: Int64::new(999),
+            count_increment_count: Uint64::new(2),
+            count_reset_count: Uint64::new(1),
+        })?;
+
         // actual query
-        assert_eq!(query_global_statistic_data(deps.as_ref(), "whatever".to_string(), Some(&store))?, QueryAnswer::GlobalStatisticData {
-            count_increment_count: 2,
-            count_reset_count: 1,
+        assert_eq!(query_global_statistic_data(deps.as_ref(), "whatever".to_string(), DEFAULT_COUNTER_ID)?, QueryAnswer::GlobalStatisticData {
+            count_increment_count: Uint64::new(2),
+            count_reset_count: Uint64::new(1),
         });
+        assert!(query_global_statistic_data(deps.as_ref(), "whatever".to_string(), "missing").is_err());
 
         Ok(())
     }
@@ -2,14 +2,16 @@ use cosmwasm_std::{Deps, StdResult};
 use crate::msg::{QueryAnswer, UserCountUpdateHistoryEntryInResponse};
 use crate::state::user_count_update_history::{UserCountUpdateHistoryManager};
 
-pub fn query_global_user_count_update_history_entries(deps: Deps, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
-    let entries = UserCountUpdateHistoryManager::get_global_entries(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test);
+pub fn query_global_user_count_update_history_entries(deps: Deps, counter_id: &str, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let entries = UserCountUpdateHistoryManager::get_global_entries(deps.storage, counter_id, page_one_based - 1, page_size, reverse_order, suffix_4_test);
     let response_entries = entries.iter().map({|e| UserCountUpdateHistoryEntryInResponse{
         user_addr: e.user_addr.clone(),
         count_change: e.count_change,
+        resulting_count: e.resulting_count,
+        kind: e.kind.clone(),
         created_at_in_ms: e.created_at.nanos() / 1_000_000,
     }}).collect();
-    let total_count = UserCountUpdateHistoryManager::get_global_entries_total_count(deps.storage, suffix_4_test)?;
+    let total_count = UserCountUpdateHistoryManager::get_global_entries_total_count(deps.storage, counter_id, suffix_4_test)?;
     Ok(QueryAnswer::UserCountUpdateHistoryEntries {
         entries: response_entries,
         total_count,
@@ -21,8 +23,10 @@ mod tests {
     use super::*;
     use cosmwasm_std::testing::*;
     use cosmwasm_std::{Addr};
-    use crate::state::user_count_update_history::{UserCountUpdateHistoryEntry};
+    use crate::state::user_count_update_history::{UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
+    use crate::state::counters::{DEFAULT_COUNTER_ID};
     use nanoid::nanoid;
+    use crate::state::Int64;
 
     #[test]
     fn query_global_user_count_update_history_entries_works() -> StdResult<()> {
@@ -36,47 +40,59 @@ mod tests {
         let entries: Vec<UserCountUpdateHistoryEntry> = vec![
             UserCountUpdateHistoryEntry{
                 user_addr: Addr::unchecked(user_addr_1),
-                count_change: 0,
+                count_change: Int64::new(0),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             },
             UserCountUpdateHistoryEntry{
                 user_addr: Addr::unchecked(user_addr_2),
-                count_change: 0,
+                count_change: Int64::new(0),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             },
         ];
         entries.iter().for_each(|entry| {
             // save
-            assert!(UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.clone(), Some(suffix_4_test)).is_ok());
+            assert!(UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.clone(), Some(suffix_4_test)).is_ok());
         });
         // actual query
-        assert_eq!(query_global_user_count_update_history_entries(deps.as_ref(), 1, 2, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
+        assert_eq!(query_global_user_count_update_history_entries(deps.as_ref(), DEFAULT_COUNTER_ID, 1, 2, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
             entries: vec![
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr_1),
-                    count_change: 0,
+                    count_change: Int64::new(0),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr_2),
-                    count_change: 0,
+                    count_change: Int64::new(0),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
             ],
             total_count: 2,
         });
-        assert_eq!(query_global_user_count_update_history_entries(deps.as_ref(), 1, 2, true, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
+        assert_eq!(query_global_user_count_update_history_entries(deps.as_ref(), DEFAULT_COUNTER_ID, 1, 2, true, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
             entries: vec![
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr_2),
-                    count_change: 0,
+                    count_change: Int64::new(0),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr_1),
-                    count_change: 0,
+                    count_change: Int64::new(0),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
             ],
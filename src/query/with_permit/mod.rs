@@ -1,15 +1,30 @@
-use cosmwasm_std::{Deps, StdResult, StdError, Binary, to_binary};
+use cosmwasm_std::{Addr, Deps, Env, StdError, StdResult, Binary, to_binary};
 use secret_toolkit::permit::{validate, Permit};
 
-use crate::msg::{QueryAnswer, QueryWithPermit};
+use crate::msg::{ContractPermission, QueryAnswer, QueryWithPermit};
 use crate::state::PREFIX_REVOKED_PERMITS;
-use crate::state::CONFIG;
+use crate::state::{CONFIG, PERMIT_POLICY};
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::multisig::{MultisigManager};
+use crate::state::viewing_keys::{ViewingKeysManager};
+use crate::state::read_grants::{ReadGrantsManager, ReadScope};
+use crate::state::contract_authorizations::{ContractAuthorizationsManager};
+use crate::state::permits::{PermitsManager};
+use crate::state::counters::{CountersManager};
+use crate::state::bookmarked_numbers::{BookmarkedNumbersManager};
 
+mod personal_count;
 mod user_statistic_data;
 mod global_statistic_data;
 mod user_count_update_history_entries;
 mod global_user_count_update_history_entries;
 mod bookmarked_number_entries;
+mod access_list_entries;
+mod audit_log_entries;
+mod pending_proposals;
+mod pending_count_change_requests;
+mod revoked_permits;
+mod eip191;
 
 /// Returns QueryResult from validating a permit and then using its creator's address when
 /// performing the specified query
@@ -17,70 +32,129 @@ mod bookmarked_number_entries;
 /// # Arguments
 ///
 /// * `deps` - a reference to Extern containing all the contract's external dependencies
+/// * `env` - the current environment
 /// * `permit` - the permit used to authentic the query
 /// * `query` - the query to perform
 pub fn permit_query_dispatch(
     deps: Deps,
-    permit: Permit,
+    env: &Env,
+    permit: Permit<ContractPermission>,
     query: QueryWithPermit,
 ) -> StdResult<Binary> {
     // Validate permit content
     let config = CONFIG.load(deps.storage)?;
 
-    let viewer = validate(
-        deps,
-        PREFIX_REVOKED_PERMITS,
-        &permit,
-        config.contract_address.to_string(),
-        None,
-    )?;
+    let viewer = if eip191::is_eip191_permit(&permit) {
+        eip191::validate(
+            deps,
+            PREFIX_REVOKED_PERMITS,
+            &permit,
+            config.contract_address.to_string(),
+        )?
+    }
+    else {
+        validate(
+            deps,
+            PREFIX_REVOKED_PERMITS,
+            &permit,
+            config.contract_address.to_string(),
+            None,
+        )?
+    };
+    let viewer_addr = deps.api.addr_validate(&viewer)?;
+    PermitsManager::ensure_current_epoch(deps.storage, &viewer_addr, permit_epoch(&permit))?;
+    ensure_permit_within_lifetime(deps, env, &permit)?;
+    ensure_permission(&permit, &query)?;
+
+    viewer_query_dispatch(deps, env, viewer_addr, query)
+}
+
+/// Same as `permit_query_dispatch` but authenticated with viewing key
+/// Viewing keys have no scopes, all queries are allowed
+pub fn viewing_key_query_dispatch(
+    deps: Deps,
+    env: &Env,
+    address: String,
+    key: String,
+    query: QueryWithPermit,
+) -> StdResult<Binary> {
+    let viewer_addr = deps.api.addr_validate(&address)?;
+    ViewingKeysManager::check(deps.storage, &viewer_addr, &key)?;
+
+    viewer_query_dispatch(deps, env, viewer_addr, query)
+}
+
+/// Same as `permit_query_dispatch` but with a key sent to a contract authorized by `owner`
+/// Queries have no sender, the key alone grants access
+pub fn contract_authorization_query_dispatch(
+    deps: Deps,
+    env: &Env,
+    owner: String,
+    contract_address: String,
+    key: String,
+    query: QueryWithPermit,
+) -> StdResult<Binary> {
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    let contract_addr = deps.api.addr_validate(&contract_address)?;
+    let scope = own_data_read_scope(&query).ok_or_else(|| StdError::generic_err("Query not available to authorized contracts"))?;
+    ContractAuthorizationsManager::ensure_authorized(deps.storage, env, &owner_addr, &contract_addr, &key, scope)?;
+
+    viewer_query_dispatch(deps, env, owner_addr, query)
+}
 
-    // Permit validated! We can now execute the query.
+/// Caller must authenticate `viewer_addr` first
+fn viewer_query_dispatch(
+    deps: Deps,
+    env: &Env,
+    viewer_addr: Addr,
+    query: QueryWithPermit,
+) -> StdResult<Binary> {
+    let viewer = viewer_addr.to_string();
+
+    // Viewer authenticated! We can now execute the query.
     let res: QueryAnswer = match query {
-        QueryWithPermit::UserStatisticData {} => {
-            user_statistic_data::query_user_statistic_data(deps, viewer, None)?
+        QueryWithPermit::PersonalCount {} => personal_count::query_personal_count(deps, &viewer_addr)?,
+        QueryWithPermit::UserStatisticData {owner, counter_id} => {
+            let owner_addr = resolve_owner_addr(deps, env, &viewer_addr, owner, ReadScope::Statistics)?;
+            user_statistic_data::query_user_statistic_data(deps, owner_addr.to_string(), &CountersManager::resolve_counter_id(counter_id), None)?
         }
-        QueryWithPermit::GlobalStatisticData {} => {
-            // Only contract manager can check
-            if config.contract_manager != viewer {
-                return Err(StdError::generic_err("unauthorized"));
-            }
+        QueryWithPermit::GlobalStatisticData {counter_id} => {
+            // Only those who can read global data can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadGlobalData)?;
 
-            global_statistic_data::query_global_statistic_data(deps, viewer, None)?
+            global_statistic_data::query_global_statistic_data(deps, viewer, &CountersManager::resolve_counter_id(counter_id))?
         }
 
-        QueryWithPermit::UserCountUpdateHistoryEntries {page, page_size, reverse_order} => {
+        QueryWithPermit::UserCountUpdateHistoryEntries {owner, counter_id, page, page_size, reverse_order} => {
+            let owner_addr = resolve_owner_addr(deps, env, &viewer_addr, owner, ReadScope::History)?;
             let page_w_fallback = page.unwrap_or(1);
             let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
             let page_size_w_fallback = page_size.unwrap_or(10);
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
-            user_count_update_history_entries::query_user_count_update_history_entries(deps, viewer, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+            user_count_update_history_entries::query_user_count_update_history_entries(deps, &CountersManager::resolve_counter_id(counter_id), owner_addr.to_string(), valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
         }
-        QueryWithPermit::GlobalUserCountUpdateHistoryEntries {page, page_size, reverse_order} => {
-            // Only contract manager can check
-            if config.contract_manager != viewer {
-                return Err(StdError::generic_err("unauthorized"));
-            }
+        QueryWithPermit::GlobalUserCountUpdateHistoryEntries {counter_id, page, page_size, reverse_order} => {
+            // Only those who can read global data can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadGlobalData)?;
 
             let page_w_fallback = page.unwrap_or(1);
             let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
             let page_size_w_fallback = page_size.unwrap_or(10);
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
-            global_user_count_update_history_entries::query_global_user_count_update_history_entries(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+            global_user_count_update_history_entries::query_global_user_count_update_history_entries(deps, &CountersManager::resolve_counter_id(counter_id), valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
         }
 
-        QueryWithPermit::OwnedBookmarkedNumberEntries {page, page_size, reverse_order} => {
+        QueryWithPermit::OwnedBookmarkedNumberEntries {owner, page, page_size, reverse_order} => {
+            let owner_addr = resolve_owner_addr(deps, env, &viewer_addr, owner, ReadScope::Bookmarks)?;
             let page_w_fallback = page.unwrap_or(1);
             let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
             let page_size_w_fallback = page_size.unwrap_or(10);
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
-            bookmarked_number_entries::owned_entries::query_entries(deps, viewer, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+            bookmarked_number_entries::owned_entries::query_entries(deps, owner_addr.to_string(), valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
         }
         QueryWithPermit::GlobalBookmarkedNumberEntries {page, page_size, reverse_order} => {
-            // Only contract manager can check
-            if config.contract_manager != viewer {
-                return Err(StdError::generic_err("unauthorized"));
-            }
+            // Only those who can read global data can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadGlobalData)?;
 
             let page_w_fallback = page.unwrap_or(1);
             let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
@@ -88,10 +162,383 @@ pub fn permit_query_dispatch(
             let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
             bookmarked_number_entries::global_entries::query_entries(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
         }
-        QueryWithPermit::OneOwnedBookmarkedNumberEntry {entry_id} => {
+        QueryWithPermit::OneOwnedBookmarkedNumberEntry {owner, entry_id} => {
+            let owner_addr = resolve_owner_addr(deps, env, &viewer_addr, owner, ReadScope::Bookmarks)?;
+            bookmarked_number_entries::one_owned_entry::query(deps, owner_addr.to_string(), entry_id, None)?
+        }
+        QueryWithPermit::SharedBookmarkedNumberEntry {entry_id} => {
+            // Entries taken down by moderators stay visible to their owners only
+            if BookmarkedNumbersManager::get_moderation_record(deps.storage, &entry_id, None).is_some() {
+                return Err(StdError::generic_err("Entry hidden by moderators"));
+            }
             bookmarked_number_entries::one_owned_entry::query(deps, viewer, entry_id, None)?
         }
+
+        QueryWithPermit::AccessListEntries {list, page, page_size, reverse_order} => {
+            // Only those who can manage access lists can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ManageAccessLists)?;
+
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            access_list_entries::query_access_list_entries(deps, list, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false))?
+        }
+
+        QueryWithPermit::AuditLogEntries {page, page_size, reverse_order} => {
+            // Only managers and auditors can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadAuditLog)?;
+
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            audit_log_entries::query_audit_log_entries(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+        }
+
+        QueryWithPermit::RevokedPermits {page, page_size, reverse_order} => {
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            revoked_permits::query_revoked_permits(deps, viewer_addr, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false))?
+        }
+
+        QueryWithPermit::PendingProposals {page, page_size, reverse_order} => {
+            // Only approvers and auditors can check
+            let is_approver = MultisigManager::get_approver_set(deps.storage)?.is_some_and(|s| s.is_approver(&viewer_addr));
+            if !is_approver {
+                RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadAuditLog)?;
+            }
+
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            pending_proposals::query_pending_proposals(deps, env, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+        }
+
+        QueryWithPermit::PendingCountChangeRequests {owner, page, page_size, reverse_order} => {
+            let owner_addr = resolve_owner_addr(deps, env, &viewer_addr, owner, ReadScope::History)?;
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            pending_count_change_requests::query_user_requests(deps, owner_addr, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+        }
+        QueryWithPermit::GlobalPendingCountChangeRequests {page, page_size, reverse_order} => {
+            // Only those who can read global data can check
+            RolesManager::ensure_capability(deps.storage, &viewer_addr, Capability::ReadGlobalData)?;
+
+            let page_w_fallback = page.unwrap_or(1);
+            let valid_page_one_based = if page_w_fallback < 1 { 1 } else { page_w_fallback };
+            let page_size_w_fallback = page_size.unwrap_or(10);
+            let valid_page_size = if (1..101).contains(&page_size_w_fallback) { page_size_w_fallback } else { 1 };
+            pending_count_change_requests::query_global_requests(deps, valid_page_one_based, valid_page_size, reverse_order.unwrap_or(false), None)?
+        }
     };
 
     to_binary(&res)
 }
+
+/// Data owner of queries accepting `owner`, defaults to viewer
+fn resolve_owner_addr(deps: Deps, env: &Env, viewer_addr: &Addr, owner: Option<String>, scope: ReadScope) -> StdResult<Addr> {
+    let Some(owner) = owner else {
+        return Ok(viewer_addr.clone());
+    };
+    let owner_addr = deps.api.addr_validate(&owner)?;
+    ReadGrantsManager::ensure_read_access(deps.storage, env, &owner_addr, viewer_addr, scope)?;
+
+    Ok(owner_addr)
+}
+
+/// Only queries of viewer's own data, since roles and read grants of viewer should not be usable by others
+fn own_data_read_scope(query: &QueryWithPermit) -> Option<ReadScope> {
+    match query {
+        QueryWithPermit::PersonalCount { .. } |
+        QueryWithPermit::UserStatisticData { owner: None, .. } => Some(ReadScope::Statistics),
+
+        QueryWithPermit::UserCountUpdateHistoryEntries { owner: None, .. } |
+        QueryWithPermit::PendingCountChangeRequests { owner: None, .. } => Some(ReadScope::History),
+
+        QueryWithPermit::OwnedBookmarkedNumberEntries { owner: None, .. } |
+        QueryWithPermit::OneOwnedBookmarkedNumberEntry { owner: None, .. } => Some(ReadScope::Bookmarks),
+
+        _ => None,
+    }
+}
+
+fn required_permission(query: &QueryWithPermit) -> ContractPermission {
+    match query {
+        QueryWithPermit::PersonalCount { .. } |
+        QueryWithPermit::UserStatisticData { .. } |
+        QueryWithPermit::GlobalStatisticData { .. } => ContractPermission::Statistics,
+
+        QueryWithPermit::UserCountUpdateHistoryEntries { .. } |
+        QueryWithPermit::GlobalUserCountUpdateHistoryEntries { .. } |
+        QueryWithPermit::PendingCountChangeRequests { .. } |
+        QueryWithPermit::GlobalPendingCountChangeRequests { .. } => ContractPermission::History,
+
+        QueryWithPermit::OwnedBookmarkedNumberEntries { .. } |
+        QueryWithPermit::GlobalBookmarkedNumberEntries { .. } |
+        QueryWithPermit::OneOwnedBookmarkedNumberEntry { .. } => ContractPermission::Bookmarks,
+
+        QueryWithPermit::AccessListEntries { .. } |
+        QueryWithPermit::AuditLogEntries { .. } |
+        QueryWithPermit::PendingProposals { .. } => ContractPermission::Admin,
+
+        QueryWithPermit::RevokedPermits { .. } => ContractPermission::Owner,
+
+        QueryWithPermit::SharedBookmarkedNumberEntry { entry_id } => ContractPermission::ShareBookmarkedNumberEntry(entry_id.clone()),
+    }
+}
+
+fn permit_issued_at_in_ms(permit: &Permit<ContractPermission>) -> Option<u64> {
+    permit.params.permissions.iter().find_map(|p| match p {
+        ContractPermission::IssuedAtInMs(ms) => Some(*ms),
+        _ => None,
+    })
+}
+
+fn permit_epoch(permit: &Permit<ContractPermission>) -> Option<u32> {
+    permit.params.permissions.iter().find_map(|p| match p {
+        ContractPermission::PermitEpoch(epoch) => Some(*epoch),
+        _ => None,
+    })
+}
+
+fn ensure_permit_within_lifetime(deps: Deps, env: &Env, permit: &Permit<ContractPermission>) -> StdResult<()> {
+    let issued_at_in_ms = permit_issued_at_in_ms(permit);
+    let expires_at_in_ms = permit.params.permissions.iter().find_map(|p| match p {
+        ContractPermission::ExpiresAtInMs(ms) => Some(*ms),
+        _ => None,
+    });
+    let now_in_ms = env.block.time.nanos() / 1_000_000;
+
+    if issued_at_in_ms.is_some_and(|issued_at_in_ms| now_in_ms < issued_at_in_ms) {
+        return Err(StdError::generic_err("Permit not valid yet"));
+    }
+    if expires_at_in_ms.is_some_and(|expires_at_in_ms| now_in_ms >= expires_at_in_ms) {
+        return Err(StdError::generic_err("Permit expired"));
+    }
+
+    let permit_policy = PERMIT_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    if let Some(max_permit_lifetime_in_seconds) = permit_policy.max_permit_lifetime_in_seconds {
+        let (Some(issued_at_in_ms), Some(expires_at_in_ms)) = (issued_at_in_ms, expires_at_in_ms) else {
+            return Err(StdError::generic_err("Permit must be signed with issued at and expires at permissions"));
+        };
+        let lifetime_in_ms = expires_at_in_ms.saturating_sub(issued_at_in_ms);
+        if lifetime_in_ms > max_permit_lifetime_in_seconds.saturating_mul(1_000) {
+            return Err(StdError::generic_err(format!(
+                "Permit lifetime cannot be longer than {} seconds",
+                max_permit_lifetime_in_seconds,
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn ensure_permission(permit: &Permit<ContractPermission>, query: &QueryWithPermit) -> StdResult<()> {
+    let required_permission = required_permission(query);
+    if !permit.check_permission(&required_permission) && !permit.check_permission(&ContractPermission::Owner) {
+        return Err(StdError::generic_err(format!(
+            "Permit does not include the required permission: {:?}",
+            required_permission,
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{CosmosMsg, Timestamp, WasmMsg, from_binary};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR};
+    use crate::msg::{ContractAuthorizationCallbackMsg, ExecuteMsg, InstantiateMsg, QueryMsg};
+    use crate::state::{Int64, PermitPolicy};
+    use crate::state::counters::{CounterTarget};
+    use crate::state::utils::{get_generated_ulid};
+    use secret_toolkit::permit::{PermitParams, PermitSignature, PubKey};
+
+    fn build_permit(permissions: Vec<ContractPermission>) -> Permit<ContractPermission> {
+        Permit {
+            params: PermitParams {
+                allowed_tokens: vec![],
+                permit_name: "test".to_string(),
+                chain_id: "secret-4".to_string(),
+                permissions,
+            },
+            signature: PermitSignature {
+                pub_key: PubKey {
+                    r#type: "tendermint/PubKeySecp256k1".to_string(),
+                    value: Binary::default(),
+                },
+                signature: Binary::default(),
+            },
+        }
+    }
+
+    #[test]
+    fn ensure_permit_within_lifetime_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let now_in_ms = env.block.time.nanos() / 1_000_000;
+
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![])).is_ok());
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![ContractPermission::ExpiresAtInMs(now_in_ms)])).is_err());
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![ContractPermission::IssuedAtInMs(now_in_ms + 1)])).is_err());
+
+        PERMIT_POLICY.save(deps.as_mut().storage, &PermitPolicy { max_permit_lifetime_in_seconds: Some(60) })?;
+        // Both required
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![ContractPermission::ExpiresAtInMs(now_in_ms + 1)])).is_err());
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![
+            ContractPermission::IssuedAtInMs(now_in_ms),
+            ContractPermission::ExpiresAtInMs(now_in_ms + 60_001),
+        ])).is_err());
+        assert!(ensure_permit_within_lifetime(deps.as_ref(), &env, &build_permit(vec![
+            ContractPermission::IssuedAtInMs(now_in_ms),
+            ContractPermission::ExpiresAtInMs(now_in_ms + 60_000),
+        ])).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ensure_permission_works() -> StdResult<()> {
+        let history_query = QueryWithPermit::UserCountUpdateHistoryEntries { owner: None, counter_id: None, page: None, page_size: None, reverse_order: None };
+        let audit_log_query = QueryWithPermit::AuditLogEntries { page: None, page_size: None, reverse_order: None };
+
+        let history_permit = build_permit(vec![ContractPermission::History]);
+        assert!(ensure_permission(&history_permit, &history_query).is_ok());
+        assert!(ensure_permission(&history_permit, &audit_log_query).is_err());
+        assert!(ensure_permission(&build_permit(vec![]), &history_query).is_err());
+        // Owner grants all
+        assert!(ensure_permission(&build_permit(vec![ContractPermission::Owner]), &audit_log_query).is_ok());
+
+        // Share permits only work for the entry shared
+        let share_permit = build_permit(vec![ContractPermission::ShareBookmarkedNumberEntry("entry1".to_string())]);
+        assert!(ensure_permission(&share_permit, &QueryWithPermit::SharedBookmarkedNumberEntry { entry_id: "entry1".to_string() }).is_ok());
+        assert!(ensure_permission(&share_permit, &QueryWithPermit::SharedBookmarkedNumberEntry { entry_id: "entry2".to_string() }).is_err());
+        assert!(ensure_permission(&share_permit, &QueryWithPermit::OwnedBookmarkedNumberEntries { owner: None, page: None, page_size: None, reverse_order: None }).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn contract_authorization_query_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), init_msg)?;
+        let increment_msg = ExecuteMsg::Increment { count: Some(3), mark_history_as_public: None, counter_id: None, target: Some(CounterTarget::Personal) };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info("user", &[]), increment_msg)?;
+
+        let authorize_msg = ExecuteMsg::AuthorizeContract {
+            contract_address: "partner_contract".to_string(),
+            code_hash: "code_hash".to_string(),
+            scopes: vec![ReadScope::Statistics],
+            expires_at_in_ms: None,
+        };
+        let res = crate::execute(deps.as_mut(), env.clone(), mock_info("user", &[]), authorize_msg)?;
+        let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = &res.messages[0].msg else {
+            panic!("Expected callback message");
+        };
+        let ContractAuthorizationCallbackMsg::ReceiveContractAuthorization { key, .. } = from_binary(msg)?;
+        let build_query_msg = |owner: &str, key: &str, query: QueryWithPermit| QueryMsg::WithContractAuthorization {
+            owner: owner.to_string(),
+            contract_address: "partner_contract".to_string(),
+            key: key.to_string(),
+            query,
+        };
+
+        let res: QueryAnswer = from_binary(&crate::query(deps.as_ref(), env.clone(), build_query_msg("user", &key, QueryWithPermit::PersonalCount {}))?)?;
+        assert_eq!(res, QueryAnswer::PersonalCount {
+            counter_id: "personal:user".to_string(),
+            count: Int64::new(3),
+        });
+        // Scopes not authorized
+        let history_query = QueryWithPermit::UserCountUpdateHistoryEntries { owner: None, counter_id: None, page: None, page_size: None, reverse_order: None };
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg("user", &key, history_query)).is_err());
+        // Key only works for data of the owner authorizing
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg("other_user", &key, QueryWithPermit::PersonalCount {})).is_err());
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg("user", "wrong_key", QueryWithPermit::PersonalCount {})).is_err());
+
+        let revoke_msg = ExecuteMsg::RevokeContractAuthorization { contract_address: "partner_contract".to_string() };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info("user", &[]), revoke_msg)?;
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg("user", &key, QueryWithPermit::PersonalCount {})).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_bookmarked_number_entry_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        // Entry IDs signed in permits below depend on block time and random
+        env.block.time = Timestamp::from_seconds(1_700_000_000);
+        env.block.random = Some(Binary::from(vec![0u8; 32]));
+        let init_msg = InstantiateMsg { count: 17, contract_manager: "owner".to_string() };
+        let _res = crate::instantiate(deps.as_mut(), env.clone(), mock_info("creator", &[]), init_msg)?;
+
+        // Address of the key signing permits below
+        let entry_owner = "secret15428vq2uzwhm3taey9sr9x5vm6tk78ewm3pkn9";
+        let add_msg = ExecuteMsg::AddBookmarkNumber { number: 7, memo_text: "seven".to_string(), mark_entry_as_public: true };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info(entry_owner, &[]), add_msg.clone())?;
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info("other_user", &[]), add_msg)?;
+        let entry_id = "01HF7YAT000000000000000001";
+        let other_owner_entry_id = "01HF7YAT000000000000000002";
+        assert_eq!(get_generated_ulid(1, &env)?, entry_id);
+        assert_eq!(get_generated_ulid(2, &env)?, other_owner_entry_id);
+
+        let build_share_permit = |permit_name: &str, shared_entry_id: &str, signature: &str| Permit {
+            params: PermitParams {
+                allowed_tokens: vec![MOCK_CONTRACT_ADDR.to_string()],
+                permit_name: permit_name.to_string(),
+                chain_id: "secret-4".to_string(),
+                permissions: vec![ContractPermission::ShareBookmarkedNumberEntry(shared_entry_id.to_string())],
+            },
+            signature: PermitSignature {
+                pub_key: PubKey {
+                    r#type: "tendermint/PubKeySecp256k1".to_string(),
+                    value: Binary::from_base64("A4MYU1tUEF1Keq5gwI/EX5aHGBtP38YlvRp1P6c5f+11").unwrap(),
+                },
+                signature: Binary::from_base64(signature).unwrap(),
+            },
+        };
+        let build_query_msg = |permit: Permit<ContractPermission>, queried_entry_id: &str| QueryMsg::WithPermit {
+            permit,
+            query: QueryWithPermit::SharedBookmarkedNumberEntry { entry_id: queried_entry_id.to_string() },
+        };
+        let share_permit = build_share_permit("share", entry_id, "JyBQ0cjOO4GXsIOPU7lowKpsJF26diirZ8AHrLeUzbQv96LNd6XnR6B+FWbc/mio/NmTp4pBIQvgC+1auHmnJA==");
+
+        // Queries have no sender, anyone holding the permit can read the entry
+        let res: QueryAnswer = from_binary(&crate::query(deps.as_ref(), env.clone(), build_query_msg(share_permit.clone(), entry_id))?)?;
+        let QueryAnswer::OneBookmarkedNumberEntry { entry } = res else {
+            panic!("Unexpected query answer");
+        };
+        assert_eq!(entry.entry_id, entry_id);
+        assert_eq!(entry.owner_addr.as_str(), entry_owner);
+        assert_eq!(entry.number, 7);
+        // Only the entry shared
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg(share_permit.clone(), other_owner_entry_id)).is_err());
+        // Only entries of permit signer
+        let other_owner_entry_share_permit = build_share_permit("share_other", other_owner_entry_id, "SJCbMns2uPgDzFSC0jt1gnj5l+fwbMTHDQx+Q8Zbh9B30F9a/1W8xxPJKhJxK9dUNvuQov58vez9UC8/ptXgww==");
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg(other_owner_entry_share_permit, other_owner_entry_id)).is_err());
+
+        // Not while hidden by moderators
+        let hide_msg = ExecuteMsg::HideBookmarkedNumberEntry { entry_id: entry_id.to_string(), reason: "abuse".to_string() };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), hide_msg)?;
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg(share_permit.clone(), entry_id)).is_err());
+        let restore_msg = ExecuteMsg::RestoreBookmarkedNumberEntry { entry_id: entry_id.to_string() };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), restore_msg)?;
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg(share_permit.clone(), entry_id)).is_ok());
+
+        let revoke_msg = ExecuteMsg::RevokePermit { permit_name: "share".to_string(), padding: None };
+        let _res = crate::execute(deps.as_mut(), env.clone(), mock_info(entry_owner, &[]), revoke_msg)?;
+        assert!(crate::query(deps.as_ref(), env.clone(), build_query_msg(share_permit, entry_id)).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use cosmwasm_std::{Addr, Deps, StdResult};
+use crate::msg::{QueryAnswer, CountChangeRequestInResponse};
+use crate::state::count_change_request::{CountChangeRequest, CountChangeRequestManager};
+use crate::state::counters::{CountersManager};
+
+pub fn query_user_requests(deps: Deps, user_addr: Addr, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let requests = CountChangeRequestManager::get_user_requests(deps.storage, user_addr.clone(), page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
+    let total_count = CountChangeRequestManager::get_user_requests_total_count(deps.storage, user_addr, suffix_4_test)?;
+    Ok(QueryAnswer::PendingCountChangeRequests {
+        requests: to_response_requests(requests),
+        total_count,
+    })
+}
+
+pub fn query_global_requests(deps: Deps, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let requests = CountChangeRequestManager::get_requests(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
+    let total_count = CountChangeRequestManager::get_requests_total_count(deps.storage, suffix_4_test)?;
+    Ok(QueryAnswer::PendingCountChangeRequests {
+        requests: to_response_requests(requests),
+        total_count,
+    })
+}
+
+fn to_response_requests(requests: Vec<(String, CountChangeRequest)>) -> Vec<CountChangeRequestInResponse> {
+    requests.into_iter().map({|(request_id, r)| CountChangeRequestInResponse{
+        request_id,
+        user_addr: r.user_addr,
+        count_change: r.count_change,
+        mark_history_as_public: r.mark_history_as_public,
+        counter_id: CountersManager::resolve_counter_id(r.counter_id),
+        created_at_in_ms: r.created_at.nanos() / 1_000_000,
+    }}).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+
+    #[test]
+    fn query_user_requests_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+
+        let request_id = CountChangeRequestManager::add_request(deps.as_mut().storage, &env, CountChangeRequest {
+            user_addr: Addr::unchecked("user1"),
+            count_change: 100,
+            mark_history_as_public: false,
+            counter_id: None,
+            created_at: Default::default(),
+        }, Some(suffix_4_test))?;
+        let _other_request_id = CountChangeRequestManager::add_request(deps.as_mut().storage, &env, CountChangeRequest {
+            user_addr: Addr::unchecked("user2"),
+            count_change: 200,
+            mark_history_as_public: false,
+            counter_id: None,
+            created_at: Default::default(),
+        }, Some(suffix_4_test))?;
+
+        assert_eq!(query_user_requests(deps.as_ref(), Addr::unchecked("user1"), 1, 10, false, Some(suffix_4_test))?, QueryAnswer::PendingCountChangeRequests {
+            requests: vec![
+                CountChangeRequestInResponse{
+                    request_id,
+                    user_addr: Addr::unchecked("user1"),
+                    count_change: 100,
+                    mark_history_as_public: false,
+                    counter_id: "default".to_string(),
+                    created_at_in_ms: Default::default(),
+                },
+            ],
+            total_count: 1,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use cosmwasm_std::{Deps, Env, StdResult};
+use crate::msg::{QueryAnswer, ProposalInResponse};
+use crate::state::multisig::{MultisigManager};
+
+pub fn query_pending_proposals(deps: Deps, env: &Env, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+    let proposals = MultisigManager::get_proposals(deps.storage, page_one_based - 1, page_size, reverse_order, suffix_4_test)?;
+    let response_proposals = proposals.iter().map({|(proposal_id, p)| ProposalInResponse{
+        proposal_id: proposal_id.clone(),
+        proposer_addr: p.proposer_addr.clone(),
+        action: p.action.clone(),
+        approvals: p.approvals.clone(),
+        is_expired: p.is_expired(env),
+        created_at_in_ms: p.created_at.nanos() / 1_000_000,
+        expires_at_in_ms: p.expires_at.nanos() / 1_000_000,
+    }}).collect();
+    let total_count = MultisigManager::get_proposals_total_count(deps.storage, suffix_4_test)?;
+    Ok(QueryAnswer::PendingProposals {
+        proposals: response_proposals,
+        total_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Addr};
+    use crate::state::Int64;
+    use crate::state::multisig::{PrivilegedAction, Proposal};
+    use nanoid::nanoid;
+
+    #[test]
+    fn query_pending_proposals_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+
+        let proposal_id = MultisigManager::add_proposal(deps.as_mut().storage, &env, Proposal {
+            proposer_addr: Addr::unchecked("approver1"),
+            action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+            approvals: vec![Addr::unchecked("approver1")],
+            created_at: Default::default(),
+            expires_at: Default::default(),
+        }, Some(suffix_4_test))?;
+
+        assert_eq!(query_pending_proposals(deps.as_ref(), &env, 1, 10, false, Some(suffix_4_test))?, QueryAnswer::PendingProposals {
+            proposals: vec![
+                ProposalInResponse{
+                    proposal_id,
+                    proposer_addr: Addr::unchecked("approver1"),
+                    action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+                    approvals: vec![Addr::unchecked("approver1")],
+                    is_expired: true,
+                    created_at_in_ms: Default::default(),
+                    expires_at_in_ms: Default::default(),
+                },
+            ],
+            total_count: 1,
+        });
+
+        Ok(())
+    }
+}
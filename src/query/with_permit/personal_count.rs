@@ -0,0 +1,45 @@
+use cosmwasm_std::{Addr, Deps, StdResult};
+use crate::msg::{QueryAnswer};
+use crate::state::counters::{CountersManager};
+
+pub fn query_personal_count(deps: Deps, viewer_addr: &Addr) -> StdResult<QueryAnswer> {
+    let counter_id = CountersManager::personal_counter_id(viewer_addr);
+    // Not created until first increment
+    let count = CountersManager::get_state(deps.storage, &counter_id).map(|state| state.count).unwrap_or_default();
+    Ok(QueryAnswer::PersonalCount {
+        counter_id,
+        count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Uint64};
+    use crate::state::{Int64, State};
+
+    #[test]
+    fn query_personal_count_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let viewer_addr = Addr::unchecked("viewer");
+
+        assert_eq!(query_personal_count(deps.as_ref(), &viewer_addr)?, QueryAnswer::PersonalCount {
+            counter_id: "personal:viewer".to_string(),
+            count: Int64::new(0),
+        });
+
+        let counter_id = CountersManager::ensure_personal_counter(deps.as_mut().storage, &mock_env(), &viewer_addr)?;
+        CountersManager::save_state(deps.as_mut().storage, &counter_id, &State {
+            count: Int64::new(3),
+            count_increment_count: Uint64::new(1),
+            count_reset_count: Uint64::new(0),
+        })?;
+        assert_eq!(query_personal_count(deps.as_ref(), &viewer_addr)?, QueryAnswer::PersonalCount {
+            counter_id,
+            count: Int64::new(3),
+        });
+
+        Ok(())
+    }
+}
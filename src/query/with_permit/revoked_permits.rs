@@ -0,0 +1,44 @@
+use cosmwasm_std::{Addr, Deps, StdResult};
+use crate::msg::{QueryAnswer, RevokedPermitInResponse};
+use crate::state::permits::{PermitsManager};
+
+pub fn query_revoked_permits(deps: Deps, user_addr: Addr, page_one_based: u32, page_size: u32, reverse_order: bool) -> StdResult<QueryAnswer> {
+    let revoked_permits = PermitsManager::get_revoked_permits(deps.storage, &user_addr, page_one_based - 1, page_size, reverse_order)?;
+    let response_permits = revoked_permits.into_iter().map({|(permit_name, revoked_at)| RevokedPermitInResponse{
+        permit_name,
+        revoked_at_in_ms: revoked_at.nanos() / 1_000_000,
+    }}).collect();
+    let total_count = PermitsManager::get_revoked_permits_total_count(deps.storage, &user_addr)?;
+    Ok(QueryAnswer::RevokedPermits {
+        permits: response_permits,
+        total_count,
+        permit_epoch: PermitsManager::get_epoch(deps.storage, &user_addr),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn query_revoked_permits_works() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let user_addr = Addr::unchecked("user1");
+        PermitsManager::add_revoked_permit(deps.as_mut().storage, &env, &user_addr, &"permit1".to_string())?;
+
+        assert_eq!(query_revoked_permits(deps.as_ref(), user_addr, 1, 10, false)?, QueryAnswer::RevokedPermits {
+            permits: vec![
+                RevokedPermitInResponse{
+                    permit_name: "permit1".to_string(),
+                    revoked_at_in_ms: env.block.time.nanos() / 1_000_000,
+                },
+            ],
+            total_count: 1,
+            permit_epoch: 0,
+        });
+
+        Ok(())
+    }
+}
@@ -2,10 +2,11 @@ use cosmwasm_std::{Deps, StdResult};
 use crate::msg::{QueryAnswer, UserCountUpdateHistoryEntryInResponse};
 use crate::state::user_count_update_history::{UserCountUpdateHistoryManager};
 
-pub fn query_user_count_update_history_entries(deps: Deps, viewer: String, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
+pub fn query_user_count_update_history_entries(deps: Deps, counter_id: &str, viewer: String, page_one_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<QueryAnswer> {
     let user_addr = deps.api.addr_validate(viewer.as_str())?;
     let entries = UserCountUpdateHistoryManager::get_user_entries(
         deps.storage,
+        counter_id,
         user_addr.clone(),
         page_one_based - 1,
         page_size,
@@ -15,9 +16,11 @@ pub fn query_user_count_update_history_entries(deps: Deps, viewer: String, page_
     let response_entries = entries.iter().map({|e| UserCountUpdateHistoryEntryInResponse{
         user_addr: e.user_addr.clone(),
         count_change: e.count_change,
+        resulting_count: e.resulting_count,
+        kind: e.kind.clone(),
         created_at_in_ms: e.created_at.nanos() / 1_000_000,
     }}).collect();
-    let total_count = UserCountUpdateHistoryManager::get_user_entries_total_count(deps.storage, user_addr)?;
+    let total_count = UserCountUpdateHistoryManager::get_user_entries_total_count(deps.storage, counter_id, user_addr)?;
     Ok(QueryAnswer::UserCountUpdateHistoryEntries {
         entries: response_entries,
         total_count,
@@ -29,8 +32,10 @@ mod tests {
     use super::*;
     use cosmwasm_std::testing::*;
     use cosmwasm_std::{Addr};
-    use crate::state::user_count_update_history::{UserCountUpdateHistoryEntry};
+    use crate::state::user_count_update_history::{UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
+    use crate::state::counters::{DEFAULT_COUNTER_ID};
     use nanoid::nanoid;
+    use crate::state::Int64;
 
     #[test]
     fn query_user_count_update_history_entries_works() -> StdResult<()> {
@@ -43,54 +48,66 @@ mod tests {
         let entries: Vec<UserCountUpdateHistoryEntry> = vec![
             UserCountUpdateHistoryEntry{
                 user_addr: Addr::unchecked(user_addr),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             },
             UserCountUpdateHistoryEntry{
                 user_addr: Addr::unchecked(user_addr),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             },
         ];
         entries.iter().for_each(|entry| {
             // save
-            assert!(UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.clone(), Some(suffix_4_test)).is_ok());
+            assert!(UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.clone(), Some(suffix_4_test)).is_ok());
         });
         // actual query
-        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), user_addr.to_string(), 1, 2, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
+        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), DEFAULT_COUNTER_ID, user_addr.to_string(), 1, 2, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
             entries: vec![
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
             ],
             total_count: 2,
         });
-        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), user_addr.to_string(), 1, 2, true, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
+        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), DEFAULT_COUNTER_ID, user_addr.to_string(), 1, 2, true, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
             entries: vec![
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
                 UserCountUpdateHistoryEntryInResponse{
                     user_addr: Addr::unchecked(user_addr),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at_in_ms: Default::default(),
                 },
             ],
             total_count: 2,
         });
         // Fail query
-        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), "not_user_addr".to_string(), 1, 1, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
+        assert_eq!(query_user_count_update_history_entries(deps.as_ref(), DEFAULT_COUNTER_ID, "not_user_addr".to_string(), 1, 1, false, Some(suffix_4_test))?, QueryAnswer::UserCountUpdateHistoryEntries {
             entries: vec![],
             total_count: 0,
         });
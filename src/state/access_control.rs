@@ -0,0 +1,254 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap, Keyset};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::utils::{keyset_reverse_paging};
+
+// Not saved on instantiate, missing means open
+static ACCESS_MODE_STORE: Item<AccessMode> = Item::new(b"access_control__access_mode");
+static DENYLIST_STORE: Keyset<Addr> = Keyset::new(b"access_control__denylist");
+static ALLOWLIST_STORE: Keyset<Addr> = Keyset::new(b"access_control__allowlist");
+// Invite code => Invite code details
+static INVITE_CODE_STORE: Keymap<String, InviteCode, Json> = Keymap::new(b"access_control__invite_code");
+// User address => Number of invite codes issued by the user
+static USER_ISSUED_INVITE_CODE_COUNT_STORE: Keymap<Addr, u32> = Keymap::new(b"access_control__user_issued_invite_code_count");
+
+/// Invite codes a non-manager user can issue, each usable once
+pub const MAX_INVITE_CODES_PER_USER: u32 = 3;
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessMode {
+    #[default]
+    Open,
+    // Everyone except those on denylist
+    Denylist,
+    // Only those on allowlist, invite only
+    Allowlist,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessList {
+    Denylist,
+    Allowlist,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct InviteCode {
+    pub issuer_addr: Addr,
+    pub remaining_uses: u32,
+    pub created_at: Timestamp,
+}
+
+#[derive(Default)]
+pub struct AccessControlManager{}
+impl AccessControlManager {
+    pub fn get_access_mode(storage: &dyn Storage) -> StdResult<AccessMode> {
+        Ok(ACCESS_MODE_STORE.may_load(storage)?.unwrap_or_default())
+    }
+    pub fn set_access_mode(storage: &mut dyn Storage, access_mode: AccessMode) -> StdResult<()> {
+        ACCESS_MODE_STORE.save(storage, &access_mode)
+    }
+
+    pub fn is_allowed(storage: &dyn Storage, addr: &Addr) -> StdResult<bool> {
+        Ok(match AccessControlManager::get_access_mode(storage)? {
+            AccessMode::Open => true,
+            AccessMode::Denylist => !DENYLIST_STORE.contains(storage, addr),
+            AccessMode::Allowlist => ALLOWLIST_STORE.contains(storage, addr),
+        })
+    }
+    pub fn ensure_allowed(storage: &dyn Storage, addr: &Addr) -> StdResult<()> {
+        if !AccessControlManager::is_allowed(storage, addr)? {
+            return Err(StdError::generic_err("Address is not allowed to perform this action"));
+        }
+
+        Ok(())
+    }
+
+    pub fn add_to_list(storage: &mut dyn Storage, list: AccessList, addrs: &[Addr]) -> StdResult<()> {
+        let list_store = get_list_store(list);
+        for addr in addrs {
+            list_store.insert(storage, addr)?;
+        }
+
+        Ok(())
+    }
+    pub fn remove_from_list(storage: &mut dyn Storage, list: AccessList, addrs: &[Addr]) -> StdResult<()> {
+        let list_store = get_list_store(list);
+        for addr in addrs {
+            if list_store.contains(storage, addr) {
+                list_store.remove(storage, addr)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_list_entries(storage: &dyn Storage, list: AccessList, page_zero_based: u32, page_size: u32, reverse_order: bool) -> StdResult<Vec<Addr>> {
+        let list_store = get_list_store(list);
+
+        if reverse_order {
+            keyset_reverse_paging(list_store, storage, page_zero_based, page_size)
+        }
+        else {
+            list_store.paging(storage, page_zero_based, page_size)
+        }
+    }
+    pub fn get_list_entries_total_count(storage: &dyn Storage, list: AccessList) -> StdResult<u32> {
+        get_list_store(list).get_len(storage)
+    }
+
+    /// `max_uses` is only respected for managers, user issued codes are single use and limited per user
+    /// Returns the uses the code got
+    pub fn create_invite_code(storage: &mut dyn Storage, env: &Env, issuer_addr: &Addr, code: String, max_uses: u32, issued_by_manager: bool) -> StdResult<u32> {
+        if code.is_empty() {
+            return Err(StdError::generic_err("Invite code cannot be empty"));
+        }
+        if INVITE_CODE_STORE.contains(storage, &code) {
+            return Err(StdError::generic_err("Invite code already exists"));
+        }
+
+        let remaining_uses = if issued_by_manager {
+            if max_uses == 0 {
+                return Err(StdError::generic_err("Invite code max uses must be at least 1"));
+            }
+            max_uses
+        }
+        else {
+            let issued_count = USER_ISSUED_INVITE_CODE_COUNT_STORE.get(storage, issuer_addr).unwrap_or(0);
+            if issued_count >= MAX_INVITE_CODES_PER_USER {
+                return Err(StdError::generic_err("Invite code limit reached"));
+            }
+            USER_ISSUED_INVITE_CODE_COUNT_STORE.insert(storage, issuer_addr, &(issued_count + 1))?;
+            1
+        };
+
+        INVITE_CODE_STORE.insert(storage, &code, &InviteCode{
+            issuer_addr: issuer_addr.clone(),
+            remaining_uses,
+            created_at: env.block.time,
+        })?;
+
+        Ok(remaining_uses)
+    }
+
+    /// Adds redeemer to allowlist and consumes one use of the code
+    pub fn redeem_invite_code(storage: &mut dyn Storage, redeemer_addr: &Addr, code: String) -> StdResult<()> {
+        let mut invite_code = match INVITE_CODE_STORE.get(storage, &code) {
+            Some(invite_code) => invite_code,
+            None => return Err(StdError::generic_err("Invalid invite code")),
+        };
+        if ALLOWLIST_STORE.contains(storage, redeemer_addr) {
+            return Err(StdError::generic_err("Already on allowlist"));
+        }
+
+        invite_code.remaining_uses = invite_code.remaining_uses.checked_sub(1).
+            ok_or_else(|| StdError::generic_err("Invite code used up"))?;
+        if invite_code.remaining_uses == 0 {
+            INVITE_CODE_STORE.remove(storage, &code)?;
+        }
+        else {
+            INVITE_CODE_STORE.insert(storage, &code, &invite_code)?;
+        }
+        ALLOWLIST_STORE.insert(storage, redeemer_addr)?;
+
+        Ok(())
+    }
+}
+
+fn get_list_store(list: AccessList) -> &'static Keyset<'static, Addr> {
+    match list {
+        AccessList::Denylist => &DENYLIST_STORE,
+        AccessList::Allowlist => &ALLOWLIST_STORE,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{StdResult};
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn test_is_allowed_with_access_modes() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let denied_addr = Addr::unchecked("denied");
+        let invited_addr = Addr::unchecked("invited");
+        let other_addr = Addr::unchecked("other");
+
+        AccessControlManager::add_to_list(deps.as_mut().storage, AccessList::Denylist, &[denied_addr.clone()])?;
+        AccessControlManager::add_to_list(deps.as_mut().storage, AccessList::Allowlist, &[invited_addr.clone()])?;
+
+        // Open by default
+        assert_eq!(AccessControlManager::get_access_mode(deps.as_ref().storage)?, AccessMode::Open);
+        assert!(AccessControlManager::is_allowed(deps.as_ref().storage, &denied_addr)?);
+        assert!(AccessControlManager::is_allowed(deps.as_ref().storage, &other_addr)?);
+
+        AccessControlManager::set_access_mode(deps.as_mut().storage, AccessMode::Denylist)?;
+        assert!(!AccessControlManager::is_allowed(deps.as_ref().storage, &denied_addr)?);
+        assert!(AccessControlManager::is_allowed(deps.as_ref().storage, &other_addr)?);
+
+        AccessControlManager::set_access_mode(deps.as_mut().storage, AccessMode::Allowlist)?;
+        assert!(AccessControlManager::is_allowed(deps.as_ref().storage, &invited_addr)?);
+        assert!(!AccessControlManager::is_allowed(deps.as_ref().storage, &other_addr)?);
+
+        AccessControlManager::remove_from_list(deps.as_mut().storage, AccessList::Allowlist, &[invited_addr.clone()])?;
+        assert!(!AccessControlManager::is_allowed(deps.as_ref().storage, &invited_addr)?);
+        assert_eq!(AccessControlManager::get_list_entries_total_count(deps.as_ref().storage, AccessList::Allowlist)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_n_redeem_invite_code() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let manager_addr = Addr::unchecked("manager");
+        let user_addr = Addr::unchecked("user");
+
+        AccessControlManager::create_invite_code(deps.as_mut().storage, &env, &manager_addr, "manager_code".to_string(), 2, true)?;
+        // Duplicate code
+        assert!(AccessControlManager::create_invite_code(deps.as_mut().storage, &env, &manager_addr, "manager_code".to_string(), 2, true).is_err());
+
+        AccessControlManager::redeem_invite_code(deps.as_mut().storage, &user_addr, "manager_code".to_string())?;
+        // Cannot redeem twice
+        assert!(AccessControlManager::redeem_invite_code(deps.as_mut().storage, &user_addr, "manager_code".to_string()).is_err());
+        AccessControlManager::redeem_invite_code(deps.as_mut().storage, &Addr::unchecked("user2"), "manager_code".to_string())?;
+        // Used up
+        assert!(AccessControlManager::redeem_invite_code(deps.as_mut().storage, &Addr::unchecked("user3"), "manager_code".to_string()).is_err());
+
+        // Zero use codes cannot be created, and stored ones are rejected instead of underflowing
+        assert!(AccessControlManager::create_invite_code(deps.as_mut().storage, &env, &manager_addr, "zero_code".to_string(), 0, true).is_err());
+        INVITE_CODE_STORE.insert(deps.as_mut().storage, &"zero_code".to_string(), &InviteCode{
+            issuer_addr: manager_addr.clone(),
+            remaining_uses: 0,
+            created_at: env.block.time,
+        })?;
+        assert!(AccessControlManager::redeem_invite_code(deps.as_mut().storage, &Addr::unchecked("user3"), "zero_code".to_string()).is_err());
+        assert!(!ALLOWLIST_STORE.contains(deps.as_ref().storage, &Addr::unchecked("user3")));
+
+        // User issued codes are limited
+        for i in 0..MAX_INVITE_CODES_PER_USER {
+            AccessControlManager::create_invite_code(deps.as_mut().storage, &env, &user_addr, format!("user_code_{}", i), 100, false)?;
+        }
+        assert!(AccessControlManager::create_invite_code(deps.as_mut().storage, &env, &user_addr, "user_code_extra".to_string(), 1, false).is_err());
+        assert_eq!(INVITE_CODE_STORE.get(deps.as_ref().storage, &"user_code_0".to_string()).unwrap().remaining_uses, 1);
+
+        assert_eq!(
+            AccessControlManager::get_list_entries(deps.as_ref().storage, AccessList::Allowlist, 0, 10, false)?,
+            vec![user_addr.clone(), Addr::unchecked("user2")],
+        );
+        assert_eq!(
+            AccessControlManager::get_list_entries(deps.as_ref().storage, AccessList::Allowlist, 0, 10, true)?,
+            vec![Addr::unchecked("user2"), user_addr.clone()],
+        );
+
+        Ok(())
+    }
+}
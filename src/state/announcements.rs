@@ -0,0 +1,194 @@
+use cosmwasm_std::{Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::utils::{get_generated_ulid};
+
+pub const MAX_ANNOUNCEMENT_TITLE_LENGTH: usize = 100;
+pub const MAX_ANNOUNCEMENT_BODY_LENGTH: usize = 2000;
+
+// Expired ones are kept until deleted, only filtered out on query
+static ANNOUNCEMENT_STORE: Keymap<String, Announcement, Json> = Keymap::new(b"announcements__entry");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static ANNOUNCEMENT_NEXT_ID_STORE: Item<u64> = Item::new(b"announcements__next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnouncementSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct Announcement {
+    pub title: String,
+    pub body: String,
+    pub severity: AnnouncementSeverity,
+    // Missing means no lower/upper bound
+    pub visible_from: Option<Timestamp>,
+    pub visible_until: Option<Timestamp>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl Announcement {
+    pub fn validate(&self) -> StdResult<()> {
+        if self.title.trim().is_empty() {
+            return Err(StdError::generic_err("Title cannot be blank"));
+        }
+        if self.title.chars().count() > MAX_ANNOUNCEMENT_TITLE_LENGTH {
+            return Err(StdError::generic_err("Title too long"));
+        }
+        if self.body.chars().count() > MAX_ANNOUNCEMENT_BODY_LENGTH {
+            return Err(StdError::generic_err("Body too long"));
+        }
+        if let (Some(visible_from), Some(visible_until)) = (self.visible_from, self.visible_until) {
+            if visible_from > visible_until {
+                return Err(StdError::generic_err("visible_from cannot be later than visible_until"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_visible(&self, env: &Env) -> bool {
+        let now = env.block.time;
+        self.visible_from.is_none_or(|visible_from| now >= visible_from) &&
+            self.visible_until.is_none_or(|visible_until| now < visible_until)
+    }
+}
+
+#[derive(Default)]
+pub struct AnnouncementsManager{}
+impl AnnouncementsManager {
+    pub fn add_entry(storage: &mut dyn Storage, env: &Env, announcement: Announcement, suffix_4_test: Option<&[u8]>) -> StdResult<String> {
+        let next_sqid = get_next_generated_id(storage, env)?;
+
+        let store = if let Some(suffix) = suffix_4_test {
+            &(ANNOUNCEMENT_STORE.add_suffix(suffix))
+        } else {
+            &ANNOUNCEMENT_STORE
+        };
+        store.insert(storage, &next_sqid, &announcement)?;
+
+        Ok(next_sqid)
+    }
+
+    pub fn get_entry(storage: &dyn Storage, announcement_id: &String, suffix_4_test: Option<&[u8]>) -> Option<Announcement> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(ANNOUNCEMENT_STORE.add_suffix(suffix))
+        } else {
+            &ANNOUNCEMENT_STORE
+        };
+
+        store.get(storage, announcement_id)
+    }
+
+    pub fn update_entry(storage: &mut dyn Storage, announcement_id: &String, announcement: &Announcement, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(ANNOUNCEMENT_STORE.add_suffix(suffix))
+        } else {
+            &ANNOUNCEMENT_STORE
+        };
+
+        store.insert(storage, announcement_id, announcement)
+    }
+
+    pub fn remove_entry(storage: &mut dyn Storage, announcement_id: &String, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(ANNOUNCEMENT_STORE.add_suffix(suffix))
+        } else {
+            &ANNOUNCEMENT_STORE
+        };
+
+        store.remove(storage, announcement_id)
+    }
+
+    /// Returns visible entries of the page and total count of visible entries
+    /// Visibility depends on block time so no index can be kept, entries are expected to be few
+    pub fn get_visible_entries(storage: &dyn Storage, env: &Env, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<(Vec<(String, Announcement)>, u32)> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(ANNOUNCEMENT_STORE.add_suffix(suffix))
+        } else {
+            &ANNOUNCEMENT_STORE
+        };
+
+        let mut visible_entries = store.iter(storage)?.
+            collect::<StdResult<Vec<(String, Announcement)>>>()?.
+            into_iter().
+            filter(|(_, a)| a.is_visible(env)).
+            collect::<Vec<(String, Announcement)>>();
+        if reverse_order {
+            visible_entries.reverse();
+        }
+        let total_count = visible_entries.len() as u32;
+        let entries = visible_entries.into_iter().
+            skip((page_zero_based * page_size) as usize).
+            take(page_size as usize).
+            collect();
+
+        Ok((entries, total_count))
+    }
+}
+
+fn get_next_generated_id(storage: &mut dyn Storage, env: &Env) -> StdResult<String> {
+    let next_id_u64 = get_next_id_u64_and_advance_sequence(storage)?;
+    get_generated_ulid(next_id_u64, env)
+}
+
+fn get_next_id_u64_and_advance_sequence(storage: &mut dyn Storage) -> StdResult<u64> {
+    let next_id = ANNOUNCEMENT_NEXT_ID_STORE.load(storage).unwrap_or(1);
+    // Ensure sequence advanced
+    ANNOUNCEMENT_NEXT_ID_STORE.save(storage, &(next_id + 1))?;
+    Ok(next_id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+
+    #[test]
+    fn test_get_visible_entries() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let announcement = Announcement {
+            title: "Maintenance".to_string(),
+            body: "".to_string(),
+            severity: AnnouncementSeverity::Info,
+            visible_from: None,
+            visible_until: None,
+            created_at: env.block.time,
+            updated_at: env.block.time,
+        };
+
+        let visible_id = AnnouncementsManager::add_entry(deps.as_mut().storage, &env, announcement.clone(), Some(suffix_4_test))?;
+        // Not yet visible
+        AnnouncementsManager::add_entry(deps.as_mut().storage, &env, Announcement {
+            visible_from: Some(env.block.time.plus_seconds(1)),
+            ..announcement.clone()
+        }, Some(suffix_4_test))?;
+        // Expired
+        AnnouncementsManager::add_entry(deps.as_mut().storage, &env, Announcement {
+            visible_until: Some(env.block.time),
+            ..announcement.clone()
+        }, Some(suffix_4_test))?;
+
+        assert_eq!(
+            AnnouncementsManager::get_visible_entries(deps.as_ref().storage, &env, 0, 10, true, Some(suffix_4_test))?,
+            (vec![(visible_id, announcement.clone())], 1),
+        );
+        assert!(Announcement { title: " ".to_string(), ..announcement.clone() }.validate().is_err());
+
+        Ok(())
+    }
+}
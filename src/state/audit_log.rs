@@ -0,0 +1,178 @@
+use cosmwasm_std::{to_vec, Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::utils::{keymap_reverse_paging, get_generated_ulid};
+
+// Append only, entries are never updated or removed
+static AUDIT_LOG_ENTRY_STORE: Keymap<String, AuditLogEntry, Json> = Keymap::new(b"audit_log__entry");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static AUDIT_LOG_ENTRY_NEXT_ID_STORE: Item<u64> = Item::new(b"audit_log__next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    ResetCount,
+    AdjustCount,
+    CreateCounter,
+
+    UpdateConfig,
+    UpdateRateLimitPolicy,
+    UpdatePermitPolicy,
+    SetCountChangeApprovalThreshold,
+
+    ProposeContractManager,
+    AcceptContractManager,
+    CancelManagerProposal,
+
+    GrantRole,
+    RevokeRole,
+
+    SetPauseState,
+
+    SetAccessMode,
+    AddToAccessList,
+    RemoveFromAccessList,
+    CreateInviteCode,
+
+    HideBookmarkedNumberEntry,
+    RestoreBookmarkedNumberEntry,
+
+    SetApproverSet,
+    ProposePrivilegedAction,
+    ApproveProposal,
+
+    SetTimelockDelay,
+    QueueAction,
+    CancelQueuedAction,
+
+    ApproveCountChangeRequest,
+    RejectCountChangeRequest,
+
+    PostAnnouncement,
+    EditAnnouncement,
+    ExpireAnnouncement,
+    DeleteAnnouncement,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct AuditLogEntry {
+    pub actor_addr: Addr,
+    pub action: AuditAction,
+    // What the action applies to, e.g. address for role changes, entry ID for moderation
+    pub target: Option<String>,
+    // JSON encoded values
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: Timestamp,
+}
+
+#[derive(Default)]
+pub struct AuditLogManager{}
+impl AuditLogManager {
+    pub fn add_entry(storage: &mut dyn Storage, env: &Env, entry: AuditLogEntry, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let next_sqid = get_next_generated_id(storage, env)?;
+
+        let entry_store = if let Some(suffix) = suffix_4_test {
+            &(AUDIT_LOG_ENTRY_STORE.add_suffix(suffix))
+        } else {
+            &AUDIT_LOG_ENTRY_STORE
+        };
+        entry_store.insert(storage, &next_sqid, &entry)
+    }
+
+    pub fn get_entries(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Vec<AuditLogEntry>> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(AUDIT_LOG_ENTRY_STORE.add_suffix(suffix))
+        } else {
+            &AUDIT_LOG_ENTRY_STORE
+        };
+
+        let items = if reverse_order {
+            keymap_reverse_paging(store, storage, page_zero_based, page_size)
+        }
+        else {
+            store.paging(storage, page_zero_based, page_size)
+        };
+        Ok(items?.iter().map(|t| t.1.clone()).collect())
+    }
+    pub fn get_entries_total_count(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(AUDIT_LOG_ENTRY_STORE.add_suffix(suffix))
+        } else {
+            &AUDIT_LOG_ENTRY_STORE
+        };
+
+        store.get_len(storage)
+    }
+}
+
+pub fn to_audit_value<T: Serialize>(value: &T) -> StdResult<String> {
+    String::from_utf8(to_vec(value)?).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+fn get_next_generated_id(storage: &mut dyn Storage, env: &Env) -> StdResult<String> {
+    let next_id_u64 = get_next_id_u64_and_advance_sequence(storage)?;
+    get_generated_ulid(next_id_u64, env)
+}
+
+fn get_next_id_u64_and_advance_sequence(storage: &mut dyn Storage) -> StdResult<u64> {
+    let next_id = AUDIT_LOG_ENTRY_NEXT_ID_STORE.load(storage).unwrap_or(1);
+    // Ensure sequence advanced
+    AUDIT_LOG_ENTRY_NEXT_ID_STORE.save(storage, &(next_id + 1))?;
+    Ok(next_id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{StdResult};
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+
+    #[test]
+    fn test_add_entry_n_get_entries() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let actor_addr = Addr::unchecked("actor");
+
+        let entries: Vec<AuditLogEntry> = vec![
+            AuditLogEntry{
+                actor_addr: actor_addr.clone(),
+                action: AuditAction::ResetCount,
+                target: None,
+                old_value: Some(to_audit_value(&17)?),
+                new_value: Some(to_audit_value(&0)?),
+                created_at: Default::default(),
+            },
+            AuditLogEntry{
+                actor_addr: actor_addr.clone(),
+                action: AuditAction::GrantRole,
+                target: Some("user".to_string()),
+                old_value: Some(to_audit_value(&Vec::<String>::new())?),
+                new_value: Some(to_audit_value(&vec!["admin"])?),
+                created_at: Default::default(),
+            },
+        ];
+        entries.iter().for_each(|entry| {
+            assert!(AuditLogManager::add_entry(deps.as_mut().storage, &env, entry.clone(), Some(suffix_4_test)).is_ok());
+        });
+
+        assert_eq!(AuditLogManager::get_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 2);
+        assert_eq!(AuditLogManager::get_entries(deps.as_ref().storage, 0, 10, false, Some(suffix_4_test))?, entries);
+        assert_eq!(
+            AuditLogManager::get_entries(deps.as_ref().storage, 0, 1, true, Some(suffix_4_test))?,
+            vec![entries[1].clone()],
+        );
+        assert_eq!(entries[1].new_value, Some("[\"admin\"]".to_string()));
+
+        Ok(())
+    }
+}
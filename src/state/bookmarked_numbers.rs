@@ -18,6 +18,8 @@ static ENTRY_NEXT_ID_STORE: Item<u64> = Item::new(b"bookmarked_numbers__next_id"
 static GLOBAL_PUBLIC_ENTRY_INDEX_STORE: Keyset<String> = Keyset::new(b"bookmarked_numbers__global_public_entry_index");
 // Number => Public Entry ID set
 static NUMBER_TO_GLOBAL_PUBLIC_ENTRY_INDEX_STORE: Keyset<String> = Keyset::new(b"bookmarked_numbers__number_to_global_public_entry_index");
+// Entry ID => Moderation record, only present for entries hidden by moderators
+static MODERATION_RECORD_STORE: Keymap<String, EntryModerationRecord, Json> = Keymap::new(b"bookmarked_numbers__moderation_record");
 
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
@@ -34,6 +36,13 @@ pub struct BookmarkedNumberEntry {
     pub updated_at: Timestamp,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct EntryModerationRecord {
+    pub hidden_by: Addr,
+    pub hidden_reason: String,
+    pub hidden_at: Timestamp,
+}
+
 pub struct UpdateOneEntryPayload {
     pub entry_id: String,
 
@@ -87,13 +96,22 @@ impl BookmarkedNumbersManager {
         }
 
         let mut entry = entry_store.get(storage, &payload.entry_id).unwrap();
+        let moderation_record_store = if let Some(suffix) = suffix_4_test {
+            &(MODERATION_RECORD_STORE.add_suffix(suffix))
+        } else {
+            &MODERATION_RECORD_STORE
+        };
+        // Hidden entries are already out of public indexes, and stay out until restored
+        let is_hidden = moderation_record_store.contains(storage, &payload.entry_id);
         // region marked_as_public
 
         // region private to public
 
         if entry.marked_as_public_at.is_none() && payload.mark_entry_as_public {
             entry.marked_as_public_at = Some(env.block.time.clone());
-            add_entry_id_to_public_entry_indexes(storage, entry.number, &payload.entry_id, suffix_4_test)?;
+            if !is_hidden {
+                add_entry_id_to_public_entry_indexes(storage, entry.number, &payload.entry_id, suffix_4_test)?;
+            }
         }
 
         // endregion private to public
@@ -101,7 +119,10 @@ impl BookmarkedNumbersManager {
         // region public to private
 
         if entry.marked_as_public_at.is_some() && !payload.mark_entry_as_public {
-            if can_be_deleted_from_global_public_entry_index_without_order_change(storage, &payload.entry_id, suffix_4_test)? {
+            if is_hidden {
+                entry.marked_as_public_at = None;
+            }
+            else if can_be_deleted_from_global_public_entry_index_without_order_change(storage, &payload.entry_id, suffix_4_test)? {
                 entry.marked_as_public_at = None;
                 remove_entry_id_from_public_entry_indexes(storage, entry.number, &payload.entry_id, suffix_4_test)?;
             }
@@ -122,6 +143,80 @@ impl BookmarkedNumbersManager {
         Ok(())
     }
 
+    /// Public order of other entries might change since removal is forced
+    pub fn hide_one_entry(storage: &mut dyn Storage, env: &Env, moderator_addr: &Addr, entry_id: String, reason: String, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let entry_store = if let Some(suffix) = suffix_4_test {
+            &(ENTRY_STORE.add_suffix(suffix))
+        } else {
+            &ENTRY_STORE
+        };
+        let entry = match entry_store.get(storage, &entry_id) {
+            Some(entry) => entry,
+            None => return Err(StdError::generic_err("Entry not found")),
+        };
+        if entry.marked_as_public_at.is_none() {
+            return Err(StdError::generic_err("Only public entries can be hidden"));
+        }
+
+        let moderation_record_store = if let Some(suffix) = suffix_4_test {
+            &(MODERATION_RECORD_STORE.add_suffix(suffix))
+        } else {
+            &MODERATION_RECORD_STORE
+        };
+        if moderation_record_store.contains(storage, &entry_id) {
+            return Err(StdError::generic_err("Entry already hidden"));
+        }
+
+        remove_entry_id_from_public_entry_indexes(storage, entry.number, &entry_id, suffix_4_test)?;
+        moderation_record_store.insert(storage, &entry_id, &EntryModerationRecord{
+            hidden_by: moderator_addr.clone(),
+            hidden_reason: reason,
+            hidden_at: env.block.time,
+        })?;
+
+        Ok(())
+    }
+
+    /// Restored entries are appended to public indexes, so not in original order
+    pub fn restore_one_entry(storage: &mut dyn Storage, entry_id: String, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let entry_store = if let Some(suffix) = suffix_4_test {
+            &(ENTRY_STORE.add_suffix(suffix))
+        } else {
+            &ENTRY_STORE
+        };
+        let entry = match entry_store.get(storage, &entry_id) {
+            Some(entry) => entry,
+            None => return Err(StdError::generic_err("Entry not found")),
+        };
+
+        let moderation_record_store = if let Some(suffix) = suffix_4_test {
+            &(MODERATION_RECORD_STORE.add_suffix(suffix))
+        } else {
+            &MODERATION_RECORD_STORE
+        };
+        if !moderation_record_store.contains(storage, &entry_id) {
+            return Err(StdError::generic_err("Entry not hidden"));
+        }
+
+        moderation_record_store.remove(storage, &entry_id)?;
+        // Owner might have made it private while hidden
+        if entry.marked_as_public_at.is_some() {
+            add_entry_id_to_public_entry_indexes(storage, entry.number, &entry_id, suffix_4_test)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_moderation_record(storage: &dyn Storage, entry_id: &String, suffix_4_test: Option<&[u8]>) -> Option<EntryModerationRecord> {
+        let moderation_record_store = if let Some(suffix) = suffix_4_test {
+            &(MODERATION_RECORD_STORE.add_suffix(suffix))
+        } else {
+            &MODERATION_RECORD_STORE
+        };
+
+        moderation_record_store.get(storage, entry_id)
+    }
+
 
     pub fn get_one_owned_entry<'a>(storage: &dyn Storage, viewer_addr: Addr, entry_id: String, suffix_4_test: Option<&[u8]>) -> StdResult<BookmarkedNumberEntry> {
         let entry_store = if let Some(suffix) = suffix_4_test {
@@ -900,6 +995,95 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hide_n_restore_one_entry() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let owner_addr = Addr::unchecked("owner");
+        let moderator_addr = Addr::unchecked("moderator");
+
+        let entries: Vec<BookmarkedNumberEntry> = vec![
+            BookmarkedNumberEntry{
+                owner_addr: owner_addr.clone(),
+                number: 1,
+                memo_text: "".to_string(),
+                marked_as_public_at: Some(Timestamp::from_nanos(0)),
+
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+            BookmarkedNumberEntry{
+                owner_addr: owner_addr.clone(),
+                number: 2,
+                memo_text: "".to_string(),
+                marked_as_public_at: None,
+
+                created_at: Default::default(),
+                updated_at: Default::default(),
+            },
+        ];
+        entries.iter().for_each(|entry| {
+            BookmarkedNumbersManager::add_one_entry(deps.as_mut().storage, &env, entry.clone(), Some(suffix_4_test)).unwrap()
+        });
+        let public_entry_id = get_generated_ulid(1, &env)?;
+        let private_entry_id = get_generated_ulid(2, &env)?;
+
+        // Not found
+        assert_eq!(
+            BookmarkedNumbersManager::hide_one_entry(deps.as_mut().storage, &env, &moderator_addr, get_generated_ulid(3, &env)?, "spam".to_string(), Some(suffix_4_test)),
+            Err(StdError::generic_err("Entry not found")),
+        );
+        // Private
+        assert_eq!(
+            BookmarkedNumbersManager::hide_one_entry(deps.as_mut().storage, &env, &moderator_addr, private_entry_id.clone(), "spam".to_string(), Some(suffix_4_test)),
+            Err(StdError::generic_err("Only public entries can be hidden")),
+        );
+
+        BookmarkedNumbersManager::hide_one_entry(deps.as_mut().storage, &env, &moderator_addr, public_entry_id.clone(), "spam".to_string(), Some(suffix_4_test))?;
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 0);
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_by_number_total_count(deps.as_ref().storage, 1, Some(suffix_4_test))?, 0);
+        assert_eq!(
+            BookmarkedNumbersManager::get_moderation_record(deps.as_ref().storage, &public_entry_id, Some(suffix_4_test)),
+            Some(EntryModerationRecord{
+                hidden_by: moderator_addr.clone(),
+                hidden_reason: "spam".to_string(),
+                hidden_at: env.block.time,
+            }),
+        );
+        // Owner still sees it
+        assert_eq!(BookmarkedNumbersManager::get_owned_entries_total_count(deps.as_ref().storage, owner_addr.clone())?, 2);
+        // Cannot hide twice
+        assert!(BookmarkedNumbersManager::hide_one_entry(deps.as_mut().storage, &env, &moderator_addr, public_entry_id.clone(), "spam".to_string(), Some(suffix_4_test)).is_err());
+
+        // Owner toggling visibility does not make it public again
+        let info = mock_info(owner_addr.as_str(), &[Coin { denom: "token".to_string(), amount: Uint128::new(2) }]);
+        BookmarkedNumbersManager::update_one_entry(deps.as_mut().storage, &env, &info, UpdateOneEntryPayload{
+            entry_id: public_entry_id.clone(),
+            memo_text: "".to_string(),
+            mark_entry_as_public: false,
+        }, Some(suffix_4_test))?;
+        BookmarkedNumbersManager::update_one_entry(deps.as_mut().storage, &env, &info, UpdateOneEntryPayload{
+            entry_id: public_entry_id.clone(),
+            memo_text: "".to_string(),
+            mark_entry_as_public: true,
+        }, Some(suffix_4_test))?;
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 0);
+
+        BookmarkedNumbersManager::restore_one_entry(deps.as_mut().storage, public_entry_id.clone(), Some(suffix_4_test))?;
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 1);
+        assert_eq!(BookmarkedNumbersManager::get_public_entries_by_number_total_count(deps.as_ref().storage, 1, Some(suffix_4_test))?, 1);
+        assert_eq!(BookmarkedNumbersManager::get_moderation_record(deps.as_ref().storage, &public_entry_id, Some(suffix_4_test)), None);
+        // Cannot restore twice
+        assert_eq!(
+            BookmarkedNumbersManager::restore_one_entry(deps.as_mut().storage, public_entry_id.clone(), Some(suffix_4_test)),
+            Err(StdError::generic_err("Entry not hidden")),
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_owned_entries_and_count() -> StdResult<()> {
         let mut deps = mock_dependencies();
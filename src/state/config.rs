@@ -2,12 +2,199 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use secret_toolkit::storage::{Item};
-use cosmwasm_std::{Addr};
+use cosmwasm_std::{Addr, StdError, StdResult, Storage};
+use crate::state::Int64;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct Config {
     pub contract_manager: Addr,
     pub contract_address: Addr,
+    pub counter_policy: CounterPolicy,
+}
+
+// All bounds are inclusive, missing means unbounded
+// Same type as count so any count can be a bound
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CounterPolicy {
+    pub min_step: Option<Int64>,
+    pub max_step: Option<Int64>,
+    pub count_floor: Option<Int64>,
+    pub count_ceiling: Option<Int64>,
+    pub allow_negative_steps: bool,
+    pub max_memo_length: Option<u32>,
+}
+
+// Same as the behaviour before policies were introduced
+impl Default for CounterPolicy {
+    fn default() -> Self {
+        CounterPolicy {
+            min_step: None,
+            max_step: None,
+            count_floor: None,
+            count_ceiling: None,
+            allow_negative_steps: true,
+            max_memo_length: None,
+        }
+    }
+}
+
+impl CounterPolicy {
+    pub fn validate(&self) -> StdResult<()> {
+        if let (Some(min_step), Some(max_step)) = (self.min_step, self.max_step) {
+            if min_step > max_step {
+                return Err(StdError::generic_err("min_step cannot be larger than max_step"));
+            }
+        }
+        if let (Some(count_floor), Some(count_ceiling)) = (self.count_floor, self.count_ceiling) {
+            if count_floor > count_ceiling {
+                return Err(StdError::generic_err("count_floor cannot be larger than count_ceiling"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_step_allowed(&self, step: i32) -> StdResult<()> {
+        if step < 0 && !self.allow_negative_steps {
+            return Err(StdError::generic_err("Negative steps are not allowed"));
+        }
+        let step = Int64::from(step);
+        if self.min_step.is_some_and(|min_step| step < min_step) || self.max_step.is_some_and(|max_step| step > max_step) {
+            return Err(StdError::generic_err("Step out of allowed range"));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_count_within_bounds(&self, count: Int64) -> StdResult<()> {
+        if self.count_floor.is_some_and(|count_floor| count < count_floor) || self.count_ceiling.is_some_and(|count_ceiling| count > count_ceiling) {
+            return Err(StdError::generic_err("Count out of allowed range"));
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_memo_text_allowed(&self, memo_text: &str) -> StdResult<()> {
+        if self.max_memo_length.is_some_and(|max_memo_length| memo_text.chars().count() > max_memo_length as usize) {
+            return Err(StdError::generic_err("Memo text too long"));
+        }
+
+        Ok(())
+    }
 }
 
 pub static CONFIG: Item<Config> = Item::new(b"config");
+// Address proposed as the next contract manager, only takes effect after it accepts
+pub static PENDING_CONTRACT_MANAGER: Item<Addr> = Item::new(b"pending_contract_manager");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
+pub struct PauseState {
+    pub increment_paused: bool,
+    pub reset_paused: bool,
+    pub bookmarks_paused: bool,
+    pub public_queries_paused: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PausableOperation {
+    Increment,
+    Reset,
+    Bookmarks,
+    PublicQueries,
+}
+
+impl PauseState {
+    pub fn is_paused(&self, operation: PausableOperation) -> bool {
+        match operation {
+            PausableOperation::Increment => self.increment_paused,
+            PausableOperation::Reset => self.reset_paused,
+            PausableOperation::Bookmarks => self.bookmarks_paused,
+            PausableOperation::PublicQueries => self.public_queries_paused,
+        }
+    }
+
+    pub fn ensure_not_paused(storage: &dyn Storage, operation: PausableOperation) -> StdResult<()> {
+        let pause_state = PAUSE_STATE.may_load(storage)?.unwrap_or_default();
+        if pause_state.is_paused(operation) {
+            let operation_name = match operation {
+                PausableOperation::Increment => "Increment",
+                PausableOperation::Reset => "Reset",
+                PausableOperation::Bookmarks => "Bookmark add/update",
+                PausableOperation::PublicQueries => "Public queries",
+            };
+            return Err(StdError::generic_err(format!("{} is currently paused", operation_name)));
+        }
+
+        Ok(())
+    }
+}
+
+// Not saved on instantiate, missing means nothing paused
+pub static PAUSE_STATE: Item<PauseState> = Item::new(b"pause_state");
+
+// Missing means unlimited
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
+pub struct RateLimitPolicy {
+    // Per user
+    pub increment_cooldown_in_seconds: Option<u64>,
+    // Per user
+    pub max_bookmarks_created_per_day: Option<u32>,
+    // Across all users
+    pub max_count_changes_per_block: Option<u32>,
+}
+
+// Not saved on instantiate, missing means no limit
+pub static RATE_LIMIT_POLICY: Item<RateLimitPolicy> = Item::new(b"rate_limit_policy");
+
+// Missing means no limit
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
+pub struct PermitPolicy {
+    // When set, permits must be signed with both issued at and expires at permissions
+    pub max_permit_lifetime_in_seconds: Option<u64>,
+}
+
+// Not saved on instantiate, missing means no limit
+pub static PERMIT_POLICY: Item<PermitPolicy> = Item::new(b"permit_policy");
+
+// Not saved on instantiate, missing means all count changes are applied immediately
+// Increments with absolute count change larger than this require approval
+pub static COUNT_CHANGE_APPROVAL_THRESHOLD: Item<u32> = Item::new(b"count_change_approval_threshold");
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_policy() -> StdResult<()> {
+        let policy = CounterPolicy {
+            min_step: Some(-2),
+            max_step: Some(5),
+            count_floor: Some(0),
+            count_ceiling: Some(100),
+            allow_negative_steps: false,
+            max_memo_length: Some(3),
+        };
+        assert!(policy.validate().is_ok());
+        assert!(CounterPolicy { min_step: Some(2), max_step: Some(1), ..Default::default() }.validate().is_err());
+        assert!(CounterPolicy { count_floor: Some(2), count_ceiling: Some(1), ..Default::default() }.validate().is_err());
+
+        assert!(policy.ensure_step_allowed(5).is_ok());
+        assert!(policy.ensure_step_allowed(6).is_err());
+        // Within range but negative
+        assert!(policy.ensure_step_allowed(-1).is_err());
+
+        assert!(policy.ensure_count_within_bounds(Int64::new(0)).is_ok());
+        assert!(policy.ensure_count_within_bounds(Int64::new(-1)).is_err());
+        assert!(policy.ensure_count_within_bounds(Int64::new(101)).is_err());
+
+        // Length in characters
+        assert!(policy.ensure_memo_text_allowed("ピカチ").is_ok());
+        assert!(policy.ensure_memo_text_allowed("abcd").is_err());
+
+        // Default allows anything
+        assert!(CounterPolicy::default().ensure_step_allowed(i32::MIN).is_ok());
+
+        Ok(())
+    }
+}
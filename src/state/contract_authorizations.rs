@@ -0,0 +1,103 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::crypto::{sha_256};
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::read_grants::ReadScope;
+use crate::state::utils::{constant_time_eq, generate_random_key};
+
+pub const CONTRACT_AUTHORIZATION_KEY_PREFIX: &str = "contract_key_";
+
+// Owner address as suffix => Contract address => Authorization
+static CONTRACT_AUTHORIZATION_STORE: Keymap<Addr, ContractAuthorization, Json> = Keymap::new(b"contract_authorizations__authorization");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ContractAuthorization {
+    // Where the key was sent, not checked on queries
+    pub code_hash: String,
+    pub scopes: Vec<ReadScope>,
+    // Only hash stored, key is sent to the contract once
+    pub key_hash: [u8; 32],
+    // Missing means never expires
+    pub expires_at: Option<Timestamp>,
+    pub authorized_at: Timestamp,
+}
+
+#[derive(Default)]
+pub struct ContractAuthorizationsManager{}
+impl ContractAuthorizationsManager {
+    /// Replaces existing authorization of the same contract, returns the new key
+    pub fn authorize(storage: &mut dyn Storage, env: &Env, owner_addr: &Addr, contract_addr: &Addr, code_hash: String, scopes: Vec<ReadScope>, expires_at: Option<Timestamp>) -> StdResult<String> {
+        let key = generate_random_key(env, owner_addr, contract_addr.as_bytes(), CONTRACT_AUTHORIZATION_KEY_PREFIX);
+        CONTRACT_AUTHORIZATION_STORE.add_suffix(owner_addr.as_bytes()).insert(storage, contract_addr, &ContractAuthorization {
+            code_hash,
+            scopes,
+            key_hash: sha_256(key.as_bytes()),
+            expires_at,
+            authorized_at: env.block.time,
+        })?;
+
+        Ok(key)
+    }
+
+    pub fn revoke(storage: &mut dyn Storage, owner_addr: &Addr, contract_addr: &Addr) -> StdResult<()> {
+        let store = CONTRACT_AUTHORIZATION_STORE.add_suffix(owner_addr.as_bytes());
+        if store.get(storage, contract_addr).is_none() {
+            return Err(StdError::generic_err("Contract not authorized"));
+        }
+
+        store.remove(storage, contract_addr)
+    }
+
+    pub fn get_authorization(storage: &dyn Storage, owner_addr: &Addr, contract_addr: &Addr) -> Option<ContractAuthorization> {
+        CONTRACT_AUTHORIZATION_STORE.add_suffix(owner_addr.as_bytes()).get(storage, contract_addr)
+    }
+
+    pub fn ensure_authorized(storage: &dyn Storage, env: &Env, owner_addr: &Addr, contract_addr: &Addr, key: &str, scope: ReadScope) -> StdResult<()> {
+        let key_hash = sha_256(key.as_bytes());
+        let authorization = ContractAuthorizationsManager::get_authorization(storage, owner_addr, contract_addr);
+        // Compare against dummy hash when missing, so timing does not reveal whether it exists
+        let is_match = constant_time_eq(&key_hash, &authorization.as_ref().map_or([0u8; 32], |a| a.key_hash));
+        let allowed = is_match && authorization.is_some_and(|a| {
+            a.scopes.contains(&scope) && a.expires_at.is_none_or(|expires_at| env.block.time < expires_at)
+        });
+        if !allowed {
+            return Err(StdError::generic_err("Contract not authorized for this query or wrong key"));
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn test_contract_authorizations() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner_addr = Addr::unchecked("owner");
+        let contract_addr = Addr::unchecked("partner_contract");
+
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &contract_addr, "", ReadScope::Statistics).is_err());
+
+        let key = ContractAuthorizationsManager::authorize(deps.as_mut().storage, &env, &owner_addr, &contract_addr, "code_hash".to_string(), vec![ReadScope::Statistics], None)?;
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &contract_addr, &key, ReadScope::Statistics).is_ok());
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &contract_addr, &key, ReadScope::History).is_err());
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &contract_addr, "wrong_key", ReadScope::Statistics).is_err());
+        // Key is bound to the contract
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &Addr::unchecked("other_contract"), &key, ReadScope::Statistics).is_err());
+
+        ContractAuthorizationsManager::revoke(deps.as_mut().storage, &owner_addr, &contract_addr)?;
+        assert!(ContractAuthorizationsManager::ensure_authorized(deps.as_ref().storage, &env, &owner_addr, &contract_addr, &key, ReadScope::Statistics).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use cosmwasm_std::{Addr, Env, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap, Keyset};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::COUNT_CHANGE_APPROVAL_THRESHOLD;
+use crate::state::utils::{keyset_reverse_paging, keymap_reverse_paging, get_generated_ulid};
+
+// Only pending requests are stored, approved or rejected ones are removed
+static COUNT_CHANGE_REQUEST_STORE: Keymap<String, CountChangeRequest, Json> = Keymap::new(b"count_change_request__request");
+// User address => Request ID array
+static USER_ADDR_TO_COUNT_CHANGE_REQUEST_INDEX_STORE: Keyset<String> = Keyset::new(b"count_change_request__user_addr_index");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static COUNT_CHANGE_REQUEST_NEXT_ID_STORE: Item<u64> = Item::new(b"count_change_request__next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CountChangeRequest {
+    pub user_addr: Addr,
+    pub count_change: i32,
+    // Applied to the history entry written on approval
+    pub mark_history_as_public: bool,
+    // Missing for requests created before named counters, meaning the default counter
+    pub counter_id: Option<String>,
+    pub created_at: Timestamp,
+}
+
+#[derive(Default)]
+pub struct CountChangeRequestManager{}
+impl CountChangeRequestManager {
+    pub fn requires_approval(storage: &dyn Storage, count_change: i32) -> StdResult<bool> {
+        let threshold = COUNT_CHANGE_APPROVAL_THRESHOLD.may_load(storage)?;
+        Ok(threshold.is_some_and(|threshold| count_change.unsigned_abs() > threshold))
+    }
+
+    pub fn add_request(storage: &mut dyn Storage, env: &Env, request: CountChangeRequest, suffix_4_test: Option<&[u8]>) -> StdResult<String> {
+        let next_sqid = get_next_generated_id(storage, env)?;
+        let user_addr = request.user_addr.clone();
+
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+        store.insert(storage, &next_sqid, &request)?;
+        CountChangeRequestManager::get_user_addr_specific_index(user_addr, suffix_4_test).insert(storage, &next_sqid)?;
+
+        Ok(next_sqid)
+    }
+
+    pub fn get_request(storage: &dyn Storage, request_id: &String, suffix_4_test: Option<&[u8]>) -> Option<CountChangeRequest> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+
+        store.get(storage, request_id)
+    }
+
+    pub fn remove_request(storage: &mut dyn Storage, request_id: &String, request: &CountChangeRequest, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+        store.remove(storage, request_id)?;
+
+        CountChangeRequestManager::get_user_addr_specific_index(request.user_addr.clone(), suffix_4_test).remove(storage, request_id)
+    }
+
+    pub fn get_requests(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Vec<(String, CountChangeRequest)>> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+
+        if reverse_order {
+            keymap_reverse_paging(store, storage, page_zero_based, page_size)
+        }
+        else {
+            store.paging(storage, page_zero_based, page_size)
+        }
+    }
+    pub fn get_requests_total_count(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+
+        store.get_len(storage)
+    }
+
+    pub fn get_user_requests(storage: &dyn Storage, user_addr: Addr, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Vec<(String, CountChangeRequest)>> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(COUNT_CHANGE_REQUEST_STORE.add_suffix(suffix))
+        } else {
+            &COUNT_CHANGE_REQUEST_STORE
+        };
+
+        let user_addr_index = CountChangeRequestManager::get_user_addr_specific_index(user_addr, suffix_4_test);
+        let ids = if reverse_order {
+            keyset_reverse_paging(&user_addr_index, storage, page_zero_based, page_size)?
+        }
+        else {
+            user_addr_index.paging(storage, page_zero_based, page_size)?
+        };
+        Ok(ids.into_iter().
+            filter_map(|id| store.get(storage, &id).map(|request| (id, request))).
+            collect())
+    }
+    pub fn get_user_requests_total_count(storage: &dyn Storage, user_addr: Addr, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        let user_addr_index = CountChangeRequestManager::get_user_addr_specific_index(user_addr, suffix_4_test);
+
+        user_addr_index.get_len(storage)
+    }
+
+    fn get_user_addr_specific_index<'a>(user_addr: Addr, suffix_4_test: Option<&[u8]>) -> Keyset<'a, String> {
+        if let Some(suffix) = suffix_4_test {
+            USER_ADDR_TO_COUNT_CHANGE_REQUEST_INDEX_STORE.add_suffix(suffix).add_suffix(user_addr.as_bytes())
+        } else {
+            USER_ADDR_TO_COUNT_CHANGE_REQUEST_INDEX_STORE.add_suffix(user_addr.as_bytes())
+        }
+    }
+}
+
+fn get_next_generated_id(storage: &mut dyn Storage, env: &Env) -> StdResult<String> {
+    let next_id_u64 = get_next_id_u64_and_advance_sequence(storage)?;
+    get_generated_ulid(next_id_u64, env)
+}
+
+fn get_next_id_u64_and_advance_sequence(storage: &mut dyn Storage) -> StdResult<u64> {
+    let next_id = COUNT_CHANGE_REQUEST_NEXT_ID_STORE.load(storage).unwrap_or(1);
+    // Ensure sequence advanced
+    COUNT_CHANGE_REQUEST_NEXT_ID_STORE.save(storage, &(next_id + 1))?;
+    Ok(next_id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+
+    #[test]
+    fn test_count_change_requests() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let request = CountChangeRequest {
+            user_addr: Addr::unchecked("user1"),
+            count_change: 100,
+            mark_history_as_public: false,
+            counter_id: None,
+            created_at: env.block.time,
+        };
+
+        // No threshold means no approval needed
+        assert!(!CountChangeRequestManager::requires_approval(deps.as_ref().storage, i32::MIN)?);
+        COUNT_CHANGE_APPROVAL_THRESHOLD.save(deps.as_mut().storage, &10)?;
+        assert!(!CountChangeRequestManager::requires_approval(deps.as_ref().storage, -10)?);
+        assert!(CountChangeRequestManager::requires_approval(deps.as_ref().storage, 11)?);
+
+        let request_id = CountChangeRequestManager::add_request(deps.as_mut().storage, &env, request.clone(), Some(suffix_4_test))?;
+        let _other_request_id = CountChangeRequestManager::add_request(deps.as_mut().storage, &env, CountChangeRequest {
+            user_addr: Addr::unchecked("user2"),
+            ..request.clone()
+        }, Some(suffix_4_test))?;
+        assert_eq!(
+            CountChangeRequestManager::get_user_requests(deps.as_ref().storage, Addr::unchecked("user1"), 0, 10, false, Some(suffix_4_test))?,
+            vec![(request_id.clone(), request.clone())],
+        );
+        assert_eq!(CountChangeRequestManager::get_requests_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 2);
+
+        CountChangeRequestManager::remove_request(deps.as_mut().storage, &request_id, &request, Some(suffix_4_test))?;
+        assert_eq!(CountChangeRequestManager::get_request(deps.as_ref().storage, &request_id, Some(suffix_4_test)), None);
+        assert_eq!(CountChangeRequestManager::get_user_requests_total_count(deps.as_ref().storage, Addr::unchecked("user1"), Some(suffix_4_test))?, 0);
+        assert_eq!(CountChangeRequestManager::get_requests_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 1);
+
+        Ok(())
+    }
+}
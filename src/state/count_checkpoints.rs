@@ -0,0 +1,144 @@
+use cosmwasm_std::{StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::Int64;
+use crate::state::user_count_update_history::{UserCountUpdateHistoryManager};
+
+// Counter ID as suffix
+// History entry count => Checkpoint, in time order
+static COUNT_CHECKPOINT_STORE: Keymap<u32, CountCheckpoint, Json> = Keymap::new(b"count_checkpoints__checkpoint");
+
+/// A checkpoint is added on counter creation and every this many history entries of the counter
+pub const COUNT_CHECKPOINT_INTERVAL: u32 = 100;
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CountCheckpoint {
+    pub count: Int64,
+    // Number of history entries of the counter already applied to `count`
+    pub history_entry_count: u32,
+    pub created_at: Timestamp,
+}
+
+#[derive(Default)]
+pub struct CountCheckpointsManager{}
+impl CountCheckpointsManager {
+    pub fn add_checkpoint(storage: &mut dyn Storage, counter_id: &str, checkpoint: &CountCheckpoint, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        CountCheckpointsManager::get_counter_specific_store(counter_id, suffix_4_test).
+            insert(storage, &checkpoint.history_entry_count, checkpoint)
+    }
+
+    /// Count as of the end of the block at `time`, `None` if the counter did not exist yet or count unknown then
+    /// Binary searches checkpoints then history entries after the checkpoint found
+    pub fn get_count_at(storage: &dyn Storage, counter_id: &str, time: Timestamp, suffix_4_test: Option<&[u8]>) -> StdResult<Option<Int64>> {
+        let store = CountCheckpointsManager::get_counter_specific_store(counter_id, suffix_4_test);
+        let checkpoint_count = store.get_len(storage)?;
+        let get_checkpoint_at = |position: u32| -> StdResult<CountCheckpoint> {
+            store.paging(storage, position, 1)?.pop().map(|(_, checkpoint)| checkpoint).
+                ok_or_else(|| StdError::not_found("CountCheckpoint"))
+        };
+
+        let Some(checkpoint_position) = find_last_position_at_or_before(0, checkpoint_count, time, |position| {
+            Ok(get_checkpoint_at(position)?.created_at)
+        })? else {
+            return Ok(None);
+        };
+        let checkpoint = get_checkpoint_at(checkpoint_position)?;
+        let history_search_end = if checkpoint_position + 1 < checkpoint_count {
+            get_checkpoint_at(checkpoint_position + 1)?.history_entry_count
+        } else {
+            UserCountUpdateHistoryManager::get_global_entries_total_count(storage, counter_id, suffix_4_test)?
+        };
+
+        let entry_position = find_last_position_at_or_before(checkpoint.history_entry_count, history_search_end, time, |position| {
+            Ok(UserCountUpdateHistoryManager::get_global_entry_at(storage, counter_id, position, suffix_4_test)?.created_at)
+        })?;
+        match entry_position {
+            Some(position) => Ok(UserCountUpdateHistoryManager::get_global_entry_at(storage, counter_id, position, suffix_4_test)?.resulting_count),
+            None => Ok(Some(checkpoint.count)),
+        }
+    }
+
+    fn get_counter_specific_store<'a>(counter_id: &str, suffix_4_test: Option<&[u8]>) -> Keymap<'a, u32, CountCheckpoint, Json> {
+        let store = COUNT_CHECKPOINT_STORE.add_suffix(counter_id.as_bytes());
+        if let Some(suffix) = suffix_4_test {
+            store.add_suffix(suffix)
+        } else {
+            store
+        }
+    }
+}
+
+// Positions within `start..end` must be in time order
+fn find_last_position_at_or_before(start: u32, end: u32, time: Timestamp, get_time: impl Fn(u32) -> StdResult<Timestamp>) -> StdResult<Option<u32>> {
+    let (mut low, mut high) = (start, end);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if get_time(mid)? <= time {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(if low > start { Some(low - 1) } else { None })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{Addr};
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+    use crate::state::counters::DEFAULT_COUNTER_ID;
+    use crate::state::user_count_update_history::{UserCountUpdateHistoryEntry, UserCountUpdateHistoryEntryKind};
+
+    #[test]
+    fn test_get_count_at() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let created_at = env.block.time;
+
+        CountCheckpointsManager::add_checkpoint(deps.as_mut().storage, DEFAULT_COUNTER_ID, &CountCheckpoint {
+            count: Int64::new(10),
+            history_entry_count: 0,
+            created_at,
+        }, Some(suffix_4_test))?;
+        // One increment per second, enough for more checkpoints
+        for i in 1..=250 {
+            env.block.time = created_at.plus_seconds(i);
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, UserCountUpdateHistoryEntry {
+                user_addr: Addr::unchecked("user"),
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(10 + i as i64)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
+                created_at: env.block.time,
+                marked_as_public_at: None,
+            }, Some(suffix_4_test))?;
+        }
+
+        let count_at = |seconds_after_creation: u64| {
+            CountCheckpointsManager::get_count_at(deps.as_ref().storage, DEFAULT_COUNTER_ID, created_at.plus_seconds(seconds_after_creation), Some(suffix_4_test))
+        };
+        assert_eq!(count_at(0)?, Some(Int64::new(10)));
+        assert_eq!(count_at(1)?, Some(Int64::new(11)));
+        assert_eq!(count_at(99)?, Some(Int64::new(109)));
+        assert_eq!(count_at(100)?, Some(Int64::new(110)));
+        assert_eq!(count_at(150)?, Some(Int64::new(160)));
+        assert_eq!(count_at(250)?, Some(Int64::new(260)));
+        assert_eq!(count_at(1000)?, Some(Int64::new(260)));
+        // Before creation
+        assert_eq!(CountCheckpointsManager::get_count_at(deps.as_ref().storage, DEFAULT_COUNTER_ID, created_at.minus_seconds(1), Some(suffix_4_test))?, None);
+        // Checkpoints added along the way
+        assert_eq!(CountCheckpointsManager::get_counter_specific_store(DEFAULT_COUNTER_ID, Some(suffix_4_test)).get_len(deps.as_ref().storage)?, 3);
+
+        Ok(())
+    }
+}
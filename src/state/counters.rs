@@ -0,0 +1,209 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::State;
+use crate::state::roles::{Capability, RolesManager};
+use crate::state::count_checkpoints::{CountCheckpoint, CountCheckpointsManager};
+use crate::state::utils::{get_generated_ulid};
+
+/// Counter used when `counter_id` is not provided, the only one before named counters were introduced
+pub const DEFAULT_COUNTER_ID: &str = "default";
+// Followed by owner address, cannot collide with generated IDs
+const PERSONAL_COUNTER_ID_PREFIX: &str = "personal:";
+
+// Counter ID => State
+static COUNTER_STATE_STORE: Keymap<String, State, Json> = Keymap::new(b"counters__state");
+// Counter ID => Info
+static COUNTER_INFO_STORE: Keymap<String, CounterInfo, Json> = Keymap::new(b"counters__info");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static COUNTER_NEXT_ID_STORE: Item<u64> = Item::new(b"counters__next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct CounterInfo {
+    pub name: String,
+    // Can reset and adjust this counter besides those with the capability
+    pub manager: Option<Addr>,
+    pub created_at: Timestamp,
+}
+
+/// Which counter `Increment` applies to
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CounterTarget {
+    Shared,
+    /// Counter of the sender, only readable by them with `PersonalCount`
+    Personal,
+}
+
+/// How one is allowed to manage a counter, see `CountersManager::ensure_can_manage`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ManageAuthority {
+    /// Via `Capability::ResetCount`, goes through multisig and timelock
+    Capability,
+    /// Manager of this shared counter only, still goes through multisig and timelock
+    CounterManager,
+    /// Owner of this personal counter, acting on own data only
+    PersonalCounterOwner,
+}
+
+#[derive(Default)]
+pub struct CountersManager{}
+impl CountersManager {
+    pub fn resolve_counter_id(counter_id: Option<String>) -> String {
+        counter_id.unwrap_or_else(|| DEFAULT_COUNTER_ID.to_string())
+    }
+
+    pub fn personal_counter_id(owner_addr: &Addr) -> String {
+        format!("{}{}", PERSONAL_COUNTER_ID_PREFIX, owner_addr)
+    }
+
+    pub fn is_personal_counter_id(counter_id: &str) -> bool {
+        counter_id.starts_with(PERSONAL_COUNTER_ID_PREFIX)
+    }
+
+    /// Personal counters are created on first increment, starting from zero
+    /// Owner is set as manager so they can reset and adjust it
+    pub fn ensure_personal_counter(storage: &mut dyn Storage, env: &Env, owner_addr: &Addr) -> StdResult<String> {
+        let counter_id = CountersManager::personal_counter_id(owner_addr);
+        if !COUNTER_STATE_STORE.contains(storage, &counter_id) {
+            CountersManager::insert_counter(storage, env, &counter_id, &CounterInfo {
+                name: "Personal".to_string(),
+                manager: Some(owner_addr.clone()),
+                created_at: env.block.time,
+            }, &State::default())?;
+        }
+
+        Ok(counter_id)
+    }
+
+    /// For the default counter on instantiate or migration
+    pub fn add_default_counter(storage: &mut dyn Storage, env: &Env, state: &State) -> StdResult<()> {
+        CountersManager::insert_counter(storage, env, DEFAULT_COUNTER_ID, &CounterInfo {
+            name: "Default".to_string(),
+            manager: None,
+            created_at: env.block.time,
+        }, state)
+    }
+
+    pub fn add_counter(storage: &mut dyn Storage, env: &Env, info: CounterInfo, state: &State) -> StdResult<String> {
+        let next_id_u64 = COUNTER_NEXT_ID_STORE.load(storage).unwrap_or(1);
+        // Ensure sequence advanced
+        COUNTER_NEXT_ID_STORE.save(storage, &(next_id_u64 + 1))?;
+        let counter_id = get_generated_ulid(next_id_u64, env)?;
+
+        CountersManager::insert_counter(storage, env, &counter_id, &info, state)?;
+
+        Ok(counter_id)
+    }
+
+    // Initial checkpoint for counts before first history entry
+    fn insert_counter(storage: &mut dyn Storage, env: &Env, counter_id: &str, info: &CounterInfo, state: &State) -> StdResult<()> {
+        COUNTER_INFO_STORE.insert(storage, &counter_id.to_string(), info)?;
+        COUNTER_STATE_STORE.insert(storage, &counter_id.to_string(), state)?;
+        CountCheckpointsManager::add_checkpoint(storage, counter_id, &CountCheckpoint {
+            count: state.count,
+            history_entry_count: 0,
+            created_at: env.block.time,
+        }, None)
+    }
+
+    pub fn get_info(storage: &dyn Storage, counter_id: &str) -> Option<CounterInfo> {
+        COUNTER_INFO_STORE.get(storage, &counter_id.to_string())
+    }
+
+    pub fn get_state(storage: &dyn Storage, counter_id: &str) -> StdResult<State> {
+        COUNTER_STATE_STORE.get(storage, &counter_id.to_string()).ok_or_else(|| StdError::generic_err("Counter not found"))
+    }
+
+    pub fn save_state(storage: &mut dyn Storage, counter_id: &str, state: &State) -> StdResult<()> {
+        if !COUNTER_STATE_STORE.contains(storage, &counter_id.to_string()) {
+            return Err(StdError::generic_err("Counter not found"));
+        }
+
+        COUNTER_STATE_STORE.insert(storage, &counter_id.to_string(), state)
+    }
+
+    pub fn is_manager(storage: &dyn Storage, counter_id: &str, addr: &Addr) -> bool {
+        CountersManager::get_info(storage, counter_id).and_then(|info| info.manager).as_ref() == Some(addr)
+    }
+
+    /// Those who can reset count in general, or the manager of this counter
+    /// Personal counters can only be managed by their owner
+    pub fn ensure_can_manage(storage: &dyn Storage, counter_id: &str, addr: &Addr) -> StdResult<ManageAuthority> {
+        if CountersManager::is_personal_counter_id(counter_id) {
+            if !CountersManager::is_manager(storage, counter_id, addr) {
+                return Err(StdError::generic_err("Counter not found"));
+            }
+            return Ok(ManageAuthority::PersonalCounterOwner);
+        }
+        if CountersManager::is_manager(storage, counter_id, addr) {
+            return Ok(ManageAuthority::CounterManager);
+        }
+        if !RolesManager::has_capability(storage, addr, Capability::ResetCount)? {
+            return Err(StdError::generic_err("Only the owner, operators or the counter manager can manage this counter"));
+        }
+
+        Ok(ManageAuthority::Capability)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use cosmwasm_std::{Uint64};
+    use crate::state::Int64;
+
+    #[test]
+    fn test_add_counter_n_manage() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let state = State {
+            count: Int64::new(3),
+            count_increment_count: Uint64::new(0),
+            count_reset_count: Uint64::new(0),
+        };
+
+        CountersManager::add_default_counter(deps.as_mut().storage, &env, &state)?;
+        let counter_id = CountersManager::add_counter(deps.as_mut().storage, &env, CounterInfo {
+            name: "Team".to_string(),
+            manager: Some(Addr::unchecked("manager")),
+            created_at: env.block.time,
+        }, &state)?;
+        assert_ne!(counter_id, DEFAULT_COUNTER_ID.to_string());
+
+        CountersManager::save_state(deps.as_mut().storage, &counter_id, &State {
+            count: Int64::new(5),
+            count_increment_count: Uint64::new(1),
+            count_reset_count: Uint64::new(0),
+        })?;
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &counter_id)?.count, Int64::new(5));
+        // Counters are separate
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &CountersManager::resolve_counter_id(None))?.count, Int64::new(3));
+        assert!(CountersManager::get_state(deps.as_ref().storage, "missing").is_err());
+        assert!(CountersManager::save_state(deps.as_mut().storage, "missing", &state).is_err());
+
+        // Managers can only manage their own counters
+        assert_eq!(CountersManager::ensure_can_manage(deps.as_ref().storage, &counter_id, &Addr::unchecked("manager"))?, ManageAuthority::CounterManager);
+        assert!(CountersManager::ensure_can_manage(deps.as_ref().storage, DEFAULT_COUNTER_ID, &Addr::unchecked("manager")).is_err());
+
+        // Personal counters are created once and managed by owner
+        let owner_addr = Addr::unchecked("owner");
+        let personal_counter_id = CountersManager::ensure_personal_counter(deps.as_mut().storage, &env, &owner_addr)?;
+        assert!(CountersManager::is_personal_counter_id(&personal_counter_id));
+        assert!(!CountersManager::is_personal_counter_id(&counter_id));
+        CountersManager::save_state(deps.as_mut().storage, &personal_counter_id, &state)?;
+        assert_eq!(CountersManager::ensure_personal_counter(deps.as_mut().storage, &env, &owner_addr)?, personal_counter_id);
+        assert_eq!(CountersManager::get_state(deps.as_ref().storage, &personal_counter_id)?.count, Int64::new(3));
+        assert_eq!(CountersManager::ensure_can_manage(deps.as_ref().storage, &personal_counter_id, &owner_addr)?, ManageAuthority::PersonalCounterOwner);
+        assert!(CountersManager::ensure_can_manage(deps.as_ref().storage, &personal_counter_id, &Addr::unchecked("manager")).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Signed counterpart of `Uint64`, not available in this version of `cosmwasm-std`
+/// Encoded as string in JSON so JS clients don't lose precision
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, JsonSchema)]
+pub struct Int64(#[schemars(with = "String")] i64);
+
+impl Int64 {
+    pub const fn new(value: i64) -> Self {
+        Int64(value)
+    }
+
+    pub const fn i64(&self) -> i64 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Int64)
+    }
+}
+
+impl From<i64> for Int64 {
+    fn from(value: i64) -> Self {
+        Int64(value)
+    }
+}
+
+impl From<i32> for Int64 {
+    fn from(value: i32) -> Self {
+        Int64(value.into())
+    }
+}
+
+impl fmt::Display for Int64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for Int64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Int64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse::<i64>().map(Int64).map_err(|e| de::Error::custom(format!("Invalid Int64 '{}': {}", value, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{from_slice, to_vec, StdResult};
+
+    #[test]
+    fn int64_json_works() -> StdResult<()> {
+        let value = Int64::new(i64::MIN);
+        let json = to_vec(&value)?;
+        assert_eq!(String::from_utf8(json.clone()).unwrap(), "\"-9223372036854775808\"");
+        assert_eq!(from_slice::<Int64>(&json)?, value);
+        // Plain numbers are rejected like `Uint64`
+        assert!(from_slice::<Int64>(b"1").is_err());
+
+        assert_eq!(Int64::new(i64::MAX).checked_add(Int64::from(1)), None);
+        assert_eq!(Int64::new(-1).checked_add(Int64::from(3)), Some(Int64::new(2)));
+
+        Ok(())
+    }
+}
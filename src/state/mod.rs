@@ -1,13 +1,29 @@
 pub mod config;
 pub mod state;
+pub mod int64;
+pub mod counters;
 pub mod schema_migrations;
 pub mod user_statistic_data;
 pub mod user_count_update_history;
+pub mod count_checkpoints;
 pub mod bookmarked_numbers;
+pub mod roles;
+pub mod access_control;
+pub mod audit_log;
+pub mod rate_limit;
+pub mod multisig;
+pub mod timelock;
+pub mod count_change_request;
+pub mod announcements;
+pub mod viewing_keys;
+pub mod read_grants;
+pub mod contract_authorizations;
+pub mod permits;
 pub(crate) mod utils;
 
-pub use config::{CONFIG, Config};
-pub use state::{STATE, State};
+pub use config::{CONFIG, Config, CounterPolicy, PENDING_CONTRACT_MANAGER, PAUSE_STATE, PauseState, PausableOperation, RATE_LIMIT_POLICY, RateLimitPolicy, COUNT_CHANGE_APPROVAL_THRESHOLD, PERMIT_POLICY, PermitPolicy};
+pub use state::{State};
+pub use int64::{Int64};
 
 /// Revoked permits prefix key
 pub const PREFIX_REVOKED_PERMITS: &str = "revoked_permits";
@@ -0,0 +1,268 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::{CounterPolicy, Int64, PermitPolicy, RateLimitPolicy};
+use crate::state::roles::Role;
+use crate::state::utils::{keymap_reverse_paging, get_generated_ulid};
+
+pub const DEFAULT_PROPOSAL_TTL_IN_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+// Missing means multisig disabled, privileged messages are executed directly
+static APPROVER_SET_STORE: Item<ApproverSet> = Item::new(b"multisig__approver_set");
+// Only pending proposals are stored, executed ones are removed
+static PROPOSAL_STORE: Keymap<String, Proposal, Json> = Keymap::new(b"multisig__proposal");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static PROPOSAL_NEXT_ID_STORE: Item<u64> = Item::new(b"multisig__proposal_next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ApproverSet {
+    pub approvers: Vec<Addr>,
+    pub threshold: u32,
+    pub proposal_ttl_in_seconds: u64,
+}
+
+impl ApproverSet {
+    pub fn is_approver(&self, addr: &Addr) -> bool {
+        self.approvers.contains(addr)
+    }
+
+    pub fn ensure_approver(&self, addr: &Addr) -> StdResult<()> {
+        if !self.is_approver(addr) {
+            return Err(StdError::generic_err("Only approvers can propose or approve"));
+        }
+
+        Ok(())
+    }
+
+    /// Approvals from addresses removed from the set no longer count
+    pub fn is_threshold_met(&self, proposal: &Proposal) -> bool {
+        let valid_approval_count = proposal.approvals.iter().filter(|a| self.is_approver(a)).count();
+        valid_approval_count >= self.threshold as usize
+    }
+}
+
+/// Messages which require approvals when multisig is enabled
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivilegedAction {
+    Reset {
+        count: Int64,
+        counter_id: Option<String>,
+    },
+    AdjustCount {
+        count_change: i32,
+        counter_id: Option<String>,
+    },
+    UpdateConfig {
+        counter_policy: CounterPolicy,
+    },
+    UpdateRateLimitPolicy {
+        rate_limit_policy: RateLimitPolicy,
+    },
+    UpdatePermitPolicy {
+        permit_policy: PermitPolicy,
+    },
+    SetCountChangeApprovalThreshold {
+        threshold: Option<u32>,
+    },
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+    SetApproverSet {
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl_in_seconds: Option<u64>,
+    },
+    SetTimelockDelay {
+        delay_in_seconds: u64,
+    },
+    CancelQueuedAction {
+        queued_action_id: String,
+    },
+}
+
+impl PrivilegedAction {
+    /// Count and config changes are delayed when timelock enabled, giving users notice
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            PrivilegedAction::Reset { .. } |
+            PrivilegedAction::AdjustCount { .. } |
+            PrivilegedAction::UpdateConfig { .. } |
+            PrivilegedAction::UpdateRateLimitPolicy { .. } |
+            PrivilegedAction::UpdatePermitPolicy { .. } |
+            PrivilegedAction::SetCountChangeApprovalThreshold { .. } |
+            PrivilegedAction::SetTimelockDelay { .. }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct Proposal {
+    pub proposer_addr: Addr,
+    pub action: PrivilegedAction,
+    pub approvals: Vec<Addr>,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+impl Proposal {
+    pub fn is_expired(&self, env: &Env) -> bool {
+        env.block.time >= self.expires_at
+    }
+}
+
+#[derive(Default)]
+pub struct MultisigManager{}
+impl MultisigManager {
+    pub fn get_approver_set(storage: &dyn Storage) -> StdResult<Option<ApproverSet>> {
+        APPROVER_SET_STORE.may_load(storage)
+    }
+
+    /// `None` disables multisig
+    pub fn set_approver_set(storage: &mut dyn Storage, approver_set: Option<ApproverSet>) -> StdResult<()> {
+        match approver_set {
+            Some(approver_set) => APPROVER_SET_STORE.save(storage, &approver_set),
+            None => {
+                APPROVER_SET_STORE.remove(storage);
+                Ok(())
+            },
+        }
+    }
+
+    pub fn add_proposal(storage: &mut dyn Storage, env: &Env, proposal: Proposal, suffix_4_test: Option<&[u8]>) -> StdResult<String> {
+        let next_sqid = get_next_generated_id(storage, env)?;
+
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+        store.insert(storage, &next_sqid, &proposal)?;
+
+        Ok(next_sqid)
+    }
+
+    pub fn get_proposal(storage: &dyn Storage, proposal_id: &String, suffix_4_test: Option<&[u8]>) -> Option<Proposal> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+
+        store.get(storage, proposal_id)
+    }
+
+    pub fn update_proposal(storage: &mut dyn Storage, proposal_id: &String, proposal: &Proposal, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+
+        store.insert(storage, proposal_id, proposal)
+    }
+
+    pub fn remove_proposal(storage: &mut dyn Storage, proposal_id: &String, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+
+        store.remove(storage, proposal_id)
+    }
+
+    pub fn get_proposals(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Vec<(String, Proposal)>> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+
+        if reverse_order {
+            keymap_reverse_paging(store, storage, page_zero_based, page_size)
+        }
+        else {
+            store.paging(storage, page_zero_based, page_size)
+        }
+    }
+    pub fn get_proposals_total_count(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(PROPOSAL_STORE.add_suffix(suffix))
+        } else {
+            &PROPOSAL_STORE
+        };
+
+        store.get_len(storage)
+    }
+}
+
+fn get_next_generated_id(storage: &mut dyn Storage, env: &Env) -> StdResult<String> {
+    let next_id_u64 = get_next_id_u64_and_advance_sequence(storage)?;
+    get_generated_ulid(next_id_u64, env)
+}
+
+fn get_next_id_u64_and_advance_sequence(storage: &mut dyn Storage) -> StdResult<u64> {
+    let next_id = PROPOSAL_NEXT_ID_STORE.load(storage).unwrap_or(1);
+    // Ensure sequence advanced
+    PROPOSAL_NEXT_ID_STORE.save(storage, &(next_id + 1))?;
+    Ok(next_id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+
+    #[test]
+    fn test_proposals() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let approver_set = ApproverSet {
+            approvers: vec![Addr::unchecked("approver1"), Addr::unchecked("approver2")],
+            threshold: 2,
+            proposal_ttl_in_seconds: 60,
+        };
+        let mut proposal = Proposal {
+            proposer_addr: Addr::unchecked("approver1"),
+            action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+            approvals: vec![Addr::unchecked("approver1")],
+            created_at: env.block.time,
+            expires_at: env.block.time.plus_seconds(60),
+        };
+
+        let proposal_id = MultisigManager::add_proposal(deps.as_mut().storage, &env, proposal.clone(), Some(suffix_4_test))?;
+        assert!(!approver_set.is_threshold_met(&proposal));
+        // Not an approver
+        proposal.approvals.push(Addr::unchecked("outsider"));
+        assert!(!approver_set.is_threshold_met(&proposal));
+        proposal.approvals.push(Addr::unchecked("approver2"));
+        assert!(approver_set.is_threshold_met(&proposal));
+
+        MultisigManager::update_proposal(deps.as_mut().storage, &proposal_id, &proposal, Some(suffix_4_test))?;
+        assert_eq!(
+            MultisigManager::get_proposals(deps.as_ref().storage, 0, 10, false, Some(suffix_4_test))?,
+            vec![(proposal_id.clone(), proposal.clone())],
+        );
+
+        MultisigManager::remove_proposal(deps.as_mut().storage, &proposal_id, Some(suffix_4_test))?;
+        assert_eq!(MultisigManager::get_proposals_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::utils::{keymap_reverse_paging};
+
+// User address as suffix => Permit name => Revoked at
+// Only for listing, `RevokedPermits` of secret-toolkit is used for validation
+static REVOKED_PERMIT_INDEX_STORE: Keymap<String, Timestamp, Json> = Keymap::new(b"permits__revoked_permit_index");
+// Missing means epoch 0, all permits accepted
+static PERMIT_EPOCH_STORE: Keymap<Addr, PermitEpoch, Json> = Keymap::new(b"permits__epoch");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct PermitEpoch {
+    // Number of times all permits were revoked
+    pub epoch: u32,
+    pub revoked_all_at: Timestamp,
+}
+
+
+#[derive(Default)]
+pub struct PermitsManager{}
+impl PermitsManager {
+    pub fn add_revoked_permit(storage: &mut dyn Storage, env: &Env, user_addr: &Addr, permit_name: &String) -> StdResult<()> {
+        REVOKED_PERMIT_INDEX_STORE.add_suffix(user_addr.as_bytes()).insert(storage, permit_name, &env.block.time)
+    }
+
+    pub fn get_revoked_permits(storage: &dyn Storage, user_addr: &Addr, page_zero_based: u32, page_size: u32, reverse_order: bool) -> StdResult<Vec<(String, Timestamp)>> {
+        let store = REVOKED_PERMIT_INDEX_STORE.add_suffix(user_addr.as_bytes());
+
+        if reverse_order {
+            keymap_reverse_paging(&store, storage, page_zero_based, page_size)
+        }
+        else {
+            store.paging(storage, page_zero_based, page_size)
+        }
+    }
+    pub fn get_revoked_permits_total_count(storage: &dyn Storage, user_addr: &Addr) -> StdResult<u32> {
+        REVOKED_PERMIT_INDEX_STORE.add_suffix(user_addr.as_bytes()).get_len(storage)
+    }
+
+    pub fn get_epoch(storage: &dyn Storage, user_addr: &Addr) -> u32 {
+        PERMIT_EPOCH_STORE.get(storage, user_addr).map_or(0, |permit_epoch| permit_epoch.epoch)
+    }
+
+    /// Rejects all permits signed for earlier epochs, returns the new epoch
+    pub fn bump_epoch(storage: &mut dyn Storage, env: &Env, user_addr: &Addr) -> StdResult<u32> {
+        let epoch = PermitsManager::get_epoch(storage, user_addr).checked_add(1).
+            ok_or_else(|| StdError::generic_err("Permit epoch overflow"))?;
+        PERMIT_EPOCH_STORE.insert(storage, user_addr, &PermitEpoch {
+            epoch,
+            revoked_all_at: env.block.time,
+        })?;
+
+        Ok(epoch)
+    }
+
+    /// Once bumped, permits must be signed with `PermitEpoch` of the current epoch
+    /// Epoch 0 also accepts permits signed without it
+    pub fn ensure_current_epoch(storage: &dyn Storage, user_addr: &Addr, permit_epoch: Option<u32>) -> StdResult<()> {
+        let epoch = PermitsManager::get_epoch(storage, user_addr);
+        if permit_epoch.unwrap_or(0) != epoch {
+            return Err(StdError::generic_err(format!(
+                "Permit not signed for current epoch, sign a new one with permit epoch {}",
+                epoch,
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn test_revoked_permits_n_epoch() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let user_addr = Addr::unchecked("user1");
+
+        PermitsManager::add_revoked_permit(deps.as_mut().storage, &env, &user_addr, &"permit1".to_string())?;
+        PermitsManager::add_revoked_permit(deps.as_mut().storage, &env, &user_addr, &"permit2".to_string())?;
+        assert_eq!(
+            PermitsManager::get_revoked_permits(deps.as_ref().storage, &user_addr, 0, 1, true)?,
+            vec![("permit2".to_string(), env.block.time)],
+        );
+        assert_eq!(PermitsManager::get_revoked_permits_total_count(deps.as_ref().storage, &Addr::unchecked("user2"))?, 0);
+
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, None).is_ok());
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, Some(0)).is_ok());
+        // Cannot be signed in advance for later epochs
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, Some(1)).is_err());
+        assert_eq!(PermitsManager::bump_epoch(deps.as_mut().storage, &env, &user_addr)?, 1);
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, None).is_err());
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, Some(0)).is_err());
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, Some(1)).is_ok());
+        assert_eq!(PermitsManager::bump_epoch(deps.as_mut().storage, &env, &user_addr)?, 2);
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &user_addr, Some(1)).is_err());
+        // Other users not affected
+        assert!(PermitsManager::ensure_current_epoch(deps.as_ref().storage, &Addr::unchecked("user2"), None).is_ok());
+
+        Ok(())
+    }
+}
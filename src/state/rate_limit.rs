@@ -0,0 +1,150 @@
+use cosmwasm_std::{Addr, Env, Storage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item};
+
+use crate::error::ContractError;
+use crate::state::RATE_LIMIT_POLICY;
+use crate::state::user_statistic_data::{USER_ACTION_TIMESTAMPS_STORE};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// Count changes made in the latest block seen, reset when block height changes
+static BLOCK_COUNT_CHANGE_TALLY_STORE: Item<BlockCountChangeTally> = Item::new(b"rate_limit__block_count_change_tally");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
+pub struct BlockCountChangeTally {
+    pub block_height: u64,
+    pub count_change_count: u32,
+}
+
+#[derive(Default)]
+pub struct RateLimitManager{}
+impl RateLimitManager {
+    /// Check limits for count increment and record it when allowed
+    pub fn check_n_record_increment(storage: &mut dyn Storage, env: &Env, user_addr: &Addr) -> Result<(), ContractError> {
+        let policy = RATE_LIMIT_POLICY.may_load(storage)?.unwrap_or_default();
+        let now = env.block.time;
+        let mut timestamps = USER_ACTION_TIMESTAMPS_STORE.get(storage, user_addr).unwrap_or_default();
+
+        if let (Some(cooldown), Some(last_incremented_at)) = (policy.increment_cooldown_in_seconds, timestamps.last_incremented_at) {
+            let retry_after = last_incremented_at.plus_seconds(cooldown);
+            if now < retry_after {
+                return Err(ContractError::RateLimited { retry_after });
+            }
+        }
+
+        let mut tally = BLOCK_COUNT_CHANGE_TALLY_STORE.may_load(storage)?.unwrap_or_default();
+        if tally.block_height != env.block.height {
+            tally = BlockCountChangeTally {
+                block_height: env.block.height,
+                count_change_count: 0,
+            };
+        }
+        if let Some(max_count_changes) = policy.max_count_changes_per_block {
+            if tally.count_change_count >= max_count_changes {
+                return Err(ContractError::BlockCountChangeLimitReached { retry_after_height: env.block.height + 1 });
+            }
+        }
+
+        tally.count_change_count += 1;
+        BLOCK_COUNT_CHANGE_TALLY_STORE.save(storage, &tally)?;
+        timestamps.last_incremented_at = Some(now);
+        USER_ACTION_TIMESTAMPS_STORE.insert(storage, user_addr, &timestamps)?;
+
+        Ok(())
+    }
+
+    /// Check limits for bookmark creation and record it when allowed
+    pub fn check_n_record_bookmark_creation(storage: &mut dyn Storage, env: &Env, user_addr: &Addr) -> Result<(), ContractError> {
+        let policy = RATE_LIMIT_POLICY.may_load(storage)?.unwrap_or_default();
+        let now = env.block.time;
+        let mut timestamps = USER_ACTION_TIMESTAMPS_STORE.get(storage, user_addr).unwrap_or_default();
+
+        // Fixed 24 hours window starting from the first creation in it
+        let window_expired = timestamps.bookmark_window_started_at.
+            is_none_or(|started_at| now >= started_at.plus_seconds(SECONDS_PER_DAY));
+        if window_expired {
+            timestamps.bookmark_window_started_at = Some(now);
+            timestamps.bookmarks_created_in_window = 0;
+        }
+        if let (Some(max_bookmarks), Some(started_at)) = (policy.max_bookmarks_created_per_day, timestamps.bookmark_window_started_at) {
+            if timestamps.bookmarks_created_in_window >= max_bookmarks {
+                return Err(ContractError::RateLimited { retry_after: started_at.plus_seconds(SECONDS_PER_DAY) });
+            }
+        }
+
+        timestamps.bookmarks_created_in_window += 1;
+        USER_ACTION_TIMESTAMPS_STORE.insert(storage, user_addr, &timestamps)?;
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use crate::state::RateLimitPolicy;
+
+    #[test]
+    fn test_check_n_record_increment() -> Result<(), ContractError> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let user_addr_1 = Addr::unchecked("user_addr_1");
+        let user_addr_2 = Addr::unchecked("user_addr_2");
+        RATE_LIMIT_POLICY.save(deps.as_mut().storage, &RateLimitPolicy {
+            increment_cooldown_in_seconds: Some(10),
+            max_bookmarks_created_per_day: None,
+            max_count_changes_per_block: Some(1),
+        })?;
+
+        RateLimitManager::check_n_record_increment(deps.as_mut().storage, &env, &user_addr_1)?;
+        // Same user in cooldown
+        assert_eq!(
+            RateLimitManager::check_n_record_increment(deps.as_mut().storage, &env, &user_addr_1),
+            Err(ContractError::RateLimited { retry_after: env.block.time.plus_seconds(10) }),
+        );
+        // Other user hits block limit
+        assert_eq!(
+            RateLimitManager::check_n_record_increment(deps.as_mut().storage, &env, &user_addr_2),
+            Err(ContractError::BlockCountChangeLimitReached { retry_after_height: env.block.height + 1 }),
+        );
+
+        env.block.height += 1;
+        env.block.time = env.block.time.plus_seconds(10);
+        RateLimitManager::check_n_record_increment(deps.as_mut().storage, &env, &user_addr_1)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_n_record_bookmark_creation() -> Result<(), ContractError> {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let user_addr = Addr::unchecked("user_addr");
+        let window_started_at = env.block.time;
+        RATE_LIMIT_POLICY.save(deps.as_mut().storage, &RateLimitPolicy {
+            increment_cooldown_in_seconds: None,
+            max_bookmarks_created_per_day: Some(2),
+            max_count_changes_per_block: None,
+        })?;
+
+        RateLimitManager::check_n_record_bookmark_creation(deps.as_mut().storage, &env, &user_addr)?;
+        env.block.time = env.block.time.plus_seconds(60);
+        RateLimitManager::check_n_record_bookmark_creation(deps.as_mut().storage, &env, &user_addr)?;
+        assert_eq!(
+            RateLimitManager::check_n_record_bookmark_creation(deps.as_mut().storage, &env, &user_addr),
+            Err(ContractError::RateLimited { retry_after: window_started_at.plus_seconds(SECONDS_PER_DAY) }),
+        );
+
+        // New window
+        env.block.time = window_started_at.plus_seconds(SECONDS_PER_DAY);
+        RateLimitManager::check_n_record_bookmark_creation(deps.as_mut().storage, &env, &user_addr)?;
+
+        Ok(())
+    }
+}
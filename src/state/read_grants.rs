@@ -0,0 +1,113 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+// Owner address as suffix => Grantee address => Grant
+static READ_GRANT_STORE: Keymap<Addr, ReadGrant, Json> = Keymap::new(b"read_grants__grant");
+
+
+/// Data of the owner which can be read by grantees
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadScope {
+    Statistics,
+    History,
+    Bookmarks,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ReadGrant {
+    pub scopes: Vec<ReadScope>,
+    // Missing means never expires
+    pub expires_at: Option<Timestamp>,
+    pub granted_at: Timestamp,
+}
+
+impl ReadGrant {
+    pub fn allows(&self, env: &Env, scope: ReadScope) -> bool {
+        self.scopes.contains(&scope) && self.expires_at.is_none_or(|expires_at| env.block.time < expires_at)
+    }
+}
+
+#[derive(Default)]
+pub struct ReadGrantsManager{}
+impl ReadGrantsManager {
+    /// Replaces existing grant to the same grantee
+    pub fn grant(storage: &mut dyn Storage, owner_addr: &Addr, grantee_addr: &Addr, grant: &ReadGrant) -> StdResult<()> {
+        if owner_addr == grantee_addr {
+            return Err(StdError::generic_err("Cannot grant read access to yourself"));
+        }
+
+        READ_GRANT_STORE.add_suffix(owner_addr.as_bytes()).insert(storage, grantee_addr, grant)
+    }
+
+    pub fn revoke(storage: &mut dyn Storage, owner_addr: &Addr, grantee_addr: &Addr) -> StdResult<()> {
+        let store = READ_GRANT_STORE.add_suffix(owner_addr.as_bytes());
+        if store.get(storage, grantee_addr).is_none() {
+            return Err(StdError::generic_err("Read access not granted to this address"));
+        }
+
+        store.remove(storage, grantee_addr)
+    }
+
+    pub fn get_grant(storage: &dyn Storage, owner_addr: &Addr, grantee_addr: &Addr) -> Option<ReadGrant> {
+        READ_GRANT_STORE.add_suffix(owner_addr.as_bytes()).get(storage, grantee_addr)
+    }
+
+    pub fn ensure_read_access(storage: &dyn Storage, env: &Env, owner_addr: &Addr, viewer_addr: &Addr, scope: ReadScope) -> StdResult<()> {
+        if owner_addr == viewer_addr {
+            return Ok(());
+        }
+        let allowed = ReadGrantsManager::get_grant(storage, owner_addr, viewer_addr).is_some_and(|g| g.allows(env, scope));
+        if !allowed {
+            return Err(StdError::generic_err("No read access to data of this owner"));
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn test_read_grants() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner_addr = Addr::unchecked("owner");
+        let grantee_addr = Addr::unchecked("grantee");
+
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &owner_addr, &grantee_addr, ReadScope::History).is_err());
+        assert!(ReadGrantsManager::grant(deps.as_mut().storage, &owner_addr, &owner_addr, &ReadGrant {
+            scopes: vec![ReadScope::History],
+            expires_at: None,
+            granted_at: env.block.time,
+        }).is_err());
+
+        ReadGrantsManager::grant(deps.as_mut().storage, &owner_addr, &grantee_addr, &ReadGrant {
+            scopes: vec![ReadScope::History],
+            expires_at: Some(env.block.time.plus_seconds(60)),
+            granted_at: env.block.time,
+        })?;
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &owner_addr, &grantee_addr, ReadScope::History).is_ok());
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &owner_addr, &grantee_addr, ReadScope::Bookmarks).is_err());
+        // Not the other way round
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &grantee_addr, &owner_addr, ReadScope::History).is_err());
+
+        let mut expired_env = mock_env();
+        expired_env.block.time = env.block.time.plus_seconds(60);
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &expired_env, &owner_addr, &grantee_addr, ReadScope::History).is_err());
+
+        ReadGrantsManager::revoke(deps.as_mut().storage, &owner_addr, &grantee_addr)?;
+        assert!(ReadGrantsManager::ensure_read_access(deps.as_ref().storage, &env, &owner_addr, &grantee_addr, ReadScope::History).is_err());
+        assert!(ReadGrantsManager::revoke(deps.as_mut().storage, &owner_addr, &grantee_addr).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use cosmwasm_std::{Addr, StdError, StdResult, Storage};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::CONFIG;
+
+// User address => Roles granted
+static ROLE_GRANT_STORE: Keymap<Addr, Vec<Role>, Json> = Keymap::new(b"roles__role_grant");
+
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Operator,
+    Moderator,
+    Auditor,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    ResetCount,
+    ManageRoles,
+    ModerateContent,
+    ReadGlobalData,
+    ManagePause,
+    ManageAccessLists,
+    ReadAuditLog,
+    ManageConfig,
+}
+
+impl Capability {
+    pub fn all() -> Vec<Capability> {
+        vec![
+            Capability::ResetCount,
+            Capability::ManageRoles,
+            Capability::ModerateContent,
+            Capability::ReadGlobalData,
+            Capability::ManagePause,
+            Capability::ManageAccessLists,
+            Capability::ReadAuditLog,
+            Capability::ManageConfig,
+        ]
+    }
+}
+
+impl Role {
+    pub fn capabilities(&self) -> Vec<Capability> {
+        match self {
+            Role::Admin => Capability::all(),
+            Role::Operator => vec![
+                Capability::ResetCount,
+                Capability::ReadGlobalData,
+                Capability::ManagePause,
+            ],
+            Role::Moderator => vec![
+                Capability::ModerateContent,
+                Capability::ManageAccessLists,
+            ],
+            Role::Auditor => vec![
+                Capability::ReadGlobalData,
+                Capability::ReadAuditLog,
+            ],
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RolesManager{}
+impl RolesManager {
+    pub fn get_roles(storage: &dyn Storage, addr: &Addr) -> Vec<Role> {
+        ROLE_GRANT_STORE.get(storage, addr).unwrap_or_default()
+    }
+
+    pub fn grant_role(storage: &mut dyn Storage, addr: &Addr, role: Role) -> StdResult<()> {
+        let mut roles = RolesManager::get_roles(storage, addr);
+        if roles.contains(&role) {
+            return Err(StdError::generic_err("Role already granted"));
+        }
+        roles.push(role);
+        ROLE_GRANT_STORE.insert(storage, addr, &roles)
+    }
+
+    pub fn revoke_role(storage: &mut dyn Storage, addr: &Addr, role: Role) -> StdResult<()> {
+        let mut roles = RolesManager::get_roles(storage, addr);
+        if !roles.contains(&role) {
+            return Err(StdError::generic_err("Role not granted"));
+        }
+        roles.retain(|r| *r != role);
+        if roles.is_empty() {
+            ROLE_GRANT_STORE.remove(storage, addr)
+        }
+        else {
+            ROLE_GRANT_STORE.insert(storage, addr, &roles)
+        }
+    }
+
+    /// Contract manager always has every capability
+    pub fn get_capabilities(storage: &dyn Storage, addr: &Addr) -> StdResult<Vec<Capability>> {
+        let config = CONFIG.load(storage)?;
+        if *addr == config.contract_manager {
+            return Ok(Capability::all());
+        }
+
+        let roles = RolesManager::get_roles(storage, addr);
+        Ok(Capability::all().into_iter().
+            filter(|c| roles.iter().any(|r| r.capabilities().contains(c))).
+            collect())
+    }
+
+    pub fn has_capability(storage: &dyn Storage, addr: &Addr, capability: Capability) -> StdResult<bool> {
+        Ok(RolesManager::get_capabilities(storage, addr)?.contains(&capability))
+    }
+
+    pub fn ensure_capability(storage: &dyn Storage, addr: &Addr, capability: Capability) -> StdResult<()> {
+        if !RolesManager::has_capability(storage, addr, capability)? {
+            return Err(StdError::generic_err("unauthorized"));
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{StdResult};
+    use cosmwasm_std::testing::*;
+    use crate::state::Config;
+
+    #[test]
+    fn test_grant_n_revoke_role() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let addr = Addr::unchecked("moderator");
+
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &addr), vec![]);
+        RolesManager::grant_role(deps.as_mut().storage, &addr, Role::Moderator)?;
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &addr), vec![Role::Moderator]);
+        // Duplicate grant
+        assert!(RolesManager::grant_role(deps.as_mut().storage, &addr, Role::Moderator).is_err());
+
+        RolesManager::grant_role(deps.as_mut().storage, &addr, Role::Auditor)?;
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &addr), vec![Role::Moderator, Role::Auditor]);
+
+        RolesManager::revoke_role(deps.as_mut().storage, &addr, Role::Moderator)?;
+        assert_eq!(RolesManager::get_roles(deps.as_ref().storage, &addr), vec![Role::Auditor]);
+        // Revoke role not granted
+        assert!(RolesManager::revoke_role(deps.as_mut().storage, &addr, Role::Moderator).is_err());
+
+        RolesManager::revoke_role(deps.as_mut().storage, &addr, Role::Auditor)?;
+        assert!(ROLE_GRANT_STORE.is_empty(deps.as_ref().storage)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_capabilities() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let contract_manager_addr = Addr::unchecked("contract_manager");
+        let operator_addr = Addr::unchecked("operator");
+        let user_addr = Addr::unchecked("user");
+        CONFIG.save(deps.as_mut().storage, &Config {
+            contract_manager: contract_manager_addr.clone(),
+            contract_address: Addr::unchecked(MOCK_CONTRACT_ADDR),
+            counter_policy: Default::default(),
+        })?;
+        RolesManager::grant_role(deps.as_mut().storage, &operator_addr, Role::Operator)?;
+
+        assert_eq!(RolesManager::get_capabilities(deps.as_ref().storage, &contract_manager_addr)?, Capability::all());
+        assert_eq!(
+            RolesManager::get_capabilities(deps.as_ref().storage, &operator_addr)?,
+            vec![Capability::ResetCount, Capability::ReadGlobalData, Capability::ManagePause],
+        );
+        assert_eq!(RolesManager::get_capabilities(deps.as_ref().storage, &user_addr)?, vec![]);
+
+        assert!(RolesManager::ensure_capability(deps.as_ref().storage, &operator_addr, Capability::ResetCount).is_ok());
+        assert!(RolesManager::ensure_capability(deps.as_ref().storage, &operator_addr, Capability::ManageRoles).is_err());
+        assert!(RolesManager::ensure_capability(deps.as_ref().storage, &user_addr, Capability::ReadGlobalData).is_err());
+
+        Ok(())
+    }
+}
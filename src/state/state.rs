@@ -1,13 +1,12 @@
+use cosmwasm_std::{Uint64};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use crate::state::Int64;
 
-use secret_toolkit::storage::{Item};
-
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+// One per counter, see `CountersManager`
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq, JsonSchema)]
 pub struct State {
-    pub count: i32,
-    pub count_increment_count: i32,
-    pub count_reset_count: i32,
+    pub count: Int64,
+    pub count_increment_count: Uint64,
+    pub count_reset_count: Uint64,
 }
-
-pub static STATE: Item<State> = Item::new(b"state");
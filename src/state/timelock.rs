@@ -0,0 +1,153 @@
+use cosmwasm_std::{Addr, Env, StdResult, Storage, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use secret_toolkit::storage::{Item, Keymap};
+use secret_toolkit::serialization::{Json};
+
+use crate::state::multisig::PrivilegedAction;
+use crate::state::utils::{keymap_reverse_paging, get_generated_ulid};
+
+// Missing or zero means timelock disabled
+static TIMELOCK_DELAY_STORE: Item<u64> = Item::new(b"timelock__delay_in_seconds");
+// Only pending actions are stored, executed or cancelled ones are removed
+static QUEUED_ACTION_STORE: Keymap<String, QueuedAction, Json> = Keymap::new(b"timelock__queued_action");
+// Like a sequence, u64 since no conversion needed for using `sqids`
+static QUEUED_ACTION_NEXT_ID_STORE: Item<u64> = Item::new(b"timelock__queued_action_next_id");
+
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct QueuedAction {
+    pub queued_by: Addr,
+    pub action: PrivilegedAction,
+    pub queued_at: Timestamp,
+    pub execute_after: Timestamp,
+}
+
+impl QueuedAction {
+    pub fn is_executable(&self, env: &Env) -> bool {
+        env.block.time >= self.execute_after
+    }
+}
+
+#[derive(Default)]
+pub struct TimelockManager{}
+impl TimelockManager {
+    pub fn get_delay_in_seconds(storage: &dyn Storage) -> StdResult<u64> {
+        Ok(TIMELOCK_DELAY_STORE.may_load(storage)?.unwrap_or(0))
+    }
+
+    pub fn set_delay_in_seconds(storage: &mut dyn Storage, delay_in_seconds: u64) -> StdResult<()> {
+        TIMELOCK_DELAY_STORE.save(storage, &delay_in_seconds)
+    }
+
+    pub fn add_queued_action(storage: &mut dyn Storage, env: &Env, queued_action: QueuedAction, suffix_4_test: Option<&[u8]>) -> StdResult<String> {
+        let next_sqid = get_next_generated_id(storage, env)?;
+
+        let store = if let Some(suffix) = suffix_4_test {
+            &(QUEUED_ACTION_STORE.add_suffix(suffix))
+        } else {
+            &QUEUED_ACTION_STORE
+        };
+        store.insert(storage, &next_sqid, &queued_action)?;
+
+        Ok(next_sqid)
+    }
+
+    pub fn get_queued_action(storage: &dyn Storage, queued_action_id: &String, suffix_4_test: Option<&[u8]>) -> Option<QueuedAction> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(QUEUED_ACTION_STORE.add_suffix(suffix))
+        } else {
+            &QUEUED_ACTION_STORE
+        };
+
+        store.get(storage, queued_action_id)
+    }
+
+    pub fn remove_queued_action(storage: &mut dyn Storage, queued_action_id: &String, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(QUEUED_ACTION_STORE.add_suffix(suffix))
+        } else {
+            &QUEUED_ACTION_STORE
+        };
+
+        store.remove(storage, queued_action_id)
+    }
+
+    pub fn get_queued_actions(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> StdResult<Vec<(String, QueuedAction)>> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(QUEUED_ACTION_STORE.add_suffix(suffix))
+        } else {
+            &QUEUED_ACTION_STORE
+        };
+
+        if reverse_order {
+            keymap_reverse_paging(store, storage, page_zero_based, page_size)
+        }
+        else {
+            store.paging(storage, page_zero_based, page_size)
+        }
+    }
+    pub fn get_queued_actions_total_count(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        let store = if let Some(suffix) = suffix_4_test {
+            &(QUEUED_ACTION_STORE.add_suffix(suffix))
+        } else {
+            &QUEUED_ACTION_STORE
+        };
+
+        store.get_len(storage)
+    }
+}
+
+fn get_next_generated_id(storage: &mut dyn Storage, env: &Env) -> StdResult<String> {
+    let next_id_u64 = get_next_id_u64_and_advance_sequence(storage)?;
+    get_generated_ulid(next_id_u64, env)
+}
+
+fn get_next_id_u64_and_advance_sequence(storage: &mut dyn Storage) -> StdResult<u64> {
+    let next_id = QUEUED_ACTION_NEXT_ID_STORE.load(storage).unwrap_or(1);
+    // Ensure sequence advanced
+    QUEUED_ACTION_NEXT_ID_STORE.save(storage, &(next_id + 1))?;
+    Ok(next_id)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+    use nanoid::nanoid;
+    use crate::state::Int64;
+
+    #[test]
+    fn test_queued_actions() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let suffix_4_test_str = nanoid!();
+        let suffix_4_test = suffix_4_test_str.as_bytes();
+        let queued_action = QueuedAction {
+            queued_by: Addr::unchecked("owner"),
+            action: PrivilegedAction::Reset { count: Int64::new(0), counter_id: None },
+            queued_at: env.block.time,
+            execute_after: env.block.time.plus_seconds(60),
+        };
+
+        assert_eq!(TimelockManager::get_delay_in_seconds(deps.as_ref().storage)?, 0);
+
+        let queued_action_id = TimelockManager::add_queued_action(deps.as_mut().storage, &env, queued_action.clone(), Some(suffix_4_test))?;
+        assert!(!queued_action.is_executable(&env));
+        assert_eq!(
+            TimelockManager::get_queued_action(deps.as_ref().storage, &queued_action_id, Some(suffix_4_test)),
+            Some(queued_action.clone()),
+        );
+        assert_eq!(
+            TimelockManager::get_queued_actions(deps.as_ref().storage, 0, 10, true, Some(suffix_4_test))?,
+            vec![(queued_action_id.clone(), queued_action)],
+        );
+
+        TimelockManager::remove_queued_action(deps.as_mut().storage, &queued_action_id, Some(suffix_4_test))?;
+        assert_eq!(TimelockManager::get_queued_actions_total_count(deps.as_ref().storage, Some(suffix_4_test))?, 0);
+
+        Ok(())
+    }
+}
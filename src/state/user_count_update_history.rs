@@ -1,25 +1,45 @@
-use cosmwasm_std::{Addr, Env, StdResult, Storage, Timestamp};
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage, Timestamp};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use secret_toolkit::storage::{Item, Keymap, Keyset};
 use secret_toolkit::serialization::{Json};
 
-use crate::state::utils::{keyset_reverse_paging, keymap_reverse_paging, get_generated_ulid};
+use crate::state::Int64;
+use crate::state::count_checkpoints::{CountCheckpoint, CountCheckpointsManager, COUNT_CHECKPOINT_INTERVAL};
+use crate::state::utils::{keyset_reverse_paging, get_generated_ulid};
 
 static USER_COUNT_UPDATE_HISTORY_ENTRY_STORE: Keymap<String, UserCountUpdateHistoryEntry, Json> = Keymap::new(b"user_count_update_history__entry");
-// User address => Entry ID array
+// Counter ID + User address => Entry ID array
 static USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"user_count_update_history__user_addr_index");
+// Counter ID => Entry ID array
+static COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"user_count_update_history__counter_id_index");
 // Like a sequence, u64 since no conversion needed for using `sqids`
 static USER_COUNT_UPDATE_HISTORY_ENTRY_NEXT_ID_STORE: Item<u64> = Item::new(b"user_count_update_history__next_id");
-// Store IDs for public entries
+// Counter ID => IDs for public entries
 static GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE: Keyset<String> = Keyset::new(b"global_public_user_count_update_history_index_store");
 
 
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserCountUpdateHistoryEntryKind {
+    Increment,
+    Reset {
+        previous_count: Int64,
+        new_count: Int64,
+    },
+    // Manual correction by managers
+    Adjustment,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct UserCountUpdateHistoryEntry {
     pub user_addr: Addr,
-    pub count_change: i32,
+    pub count_change: Int64,
+    // Count of the counter after this update
+    // `None` if unknown, only for some entries added before resets were recorded
+    pub resulting_count: Option<Int64>,
+    pub kind: UserCountUpdateHistoryEntryKind,
     pub created_at: Timestamp,
     pub marked_as_public_at: Option<Timestamp>,
 }
@@ -27,7 +47,7 @@ pub struct UserCountUpdateHistoryEntry {
 #[derive(Default)]
 pub struct UserCountUpdateHistoryManager{}
 impl UserCountUpdateHistoryManager {
-    pub fn add_entry(storage: &mut dyn Storage, env: &Env, history_entry: UserCountUpdateHistoryEntry, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
+    pub fn add_entry(storage: &mut dyn Storage, env: &Env, counter_id: &str, history_entry: UserCountUpdateHistoryEntry, suffix_4_test: Option<&[u8]>) -> StdResult<()> {
         let next_sqid = get_next_generated_id(storage, env)?;
         let user_addr = history_entry.user_addr.clone();
 
@@ -37,51 +57,66 @@ impl UserCountUpdateHistoryManager {
             &USER_COUNT_UPDATE_HISTORY_ENTRY_STORE
         };
         entry_store.insert(storage, &next_sqid.clone(), &history_entry)?;
-        UserCountUpdateHistoryManager::get_user_addr_specific_index(user_addr).insert(storage, &next_sqid.clone())?;
+        let counter_index = UserCountUpdateHistoryManager::get_counter_specific_index(counter_id, suffix_4_test);
+        counter_index.insert(storage, &next_sqid.clone())?;
+        let history_entry_count = counter_index.get_len(storage)?;
+        if let (0, Some(count)) = (history_entry_count % COUNT_CHECKPOINT_INTERVAL, history_entry.resulting_count) {
+            CountCheckpointsManager::add_checkpoint(storage, counter_id, &CountCheckpoint {
+                count,
+                history_entry_count,
+                created_at: history_entry.created_at,
+            }, suffix_4_test)?;
+        }
+        UserCountUpdateHistoryManager::get_user_addr_specific_index(counter_id, user_addr).insert(storage, &next_sqid.clone())?;
         if history_entry.marked_as_public_at.is_some() {
-            let index_store = if let Some(suffix) = suffix_4_test {
-                &(GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(suffix))
-            } else {
-                &GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE
-            };
-            index_store.insert(storage, &next_sqid.clone())?;
+            UserCountUpdateHistoryManager::get_counter_specific_public_index(counter_id, suffix_4_test).insert(storage, &next_sqid.clone())?;
         }
 
         Ok(())
     }
 
-    pub fn get_global_entries<'a>(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
-        let store = if let Some(suffix) = suffix_4_test {
+    pub fn get_global_entries<'a>(storage: &dyn Storage, counter_id: &str, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
+        let entry_store = if let Some(suffix) = suffix_4_test {
             &(USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.add_suffix(suffix))
         } else {
             &USER_COUNT_UPDATE_HISTORY_ENTRY_STORE
         };
 
+        let counter_index = UserCountUpdateHistoryManager::get_counter_specific_index(counter_id, suffix_4_test);
         let items = if reverse_order {
-            keymap_reverse_paging(store, storage, page_zero_based, page_size)
+            keyset_reverse_paging(&counter_index, storage, page_zero_based, page_size)
         }
         else {
-            store.paging(storage, page_zero_based, page_size)
+            counter_index.paging(storage, page_zero_based, page_size)
         };
-        items.unwrap().iter().map(|t| t.1.clone()).collect()
+        items.unwrap().iter().
+            map(|id| entry_store.get(storage, id).unwrap()).
+            collect::<Vec<UserCountUpdateHistoryEntry>>()
     }
-    pub fn get_global_entries_total_count<'a>(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
-        let store = if let Some(suffix) = suffix_4_test {
+    /// Position in the order entries were added to the counter
+    pub fn get_global_entry_at(storage: &dyn Storage, counter_id: &str, position: u32, suffix_4_test: Option<&[u8]>) -> StdResult<UserCountUpdateHistoryEntry> {
+        let entry_store = if let Some(suffix) = suffix_4_test {
             &(USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.add_suffix(suffix))
         } else {
             &USER_COUNT_UPDATE_HISTORY_ENTRY_STORE
         };
 
-        store.get_len(storage)
+        let counter_index = UserCountUpdateHistoryManager::get_counter_specific_index(counter_id, suffix_4_test);
+        counter_index.paging(storage, position, 1)?.first().
+            and_then(|id| entry_store.get(storage, id)).
+            ok_or_else(|| StdError::not_found("UserCountUpdateHistoryEntry"))
     }
-    pub fn get_user_entries<'a>(storage: &dyn Storage, user_addr: Addr, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
+    pub fn get_global_entries_total_count<'a>(storage: &dyn Storage, counter_id: &str, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        UserCountUpdateHistoryManager::get_counter_specific_index(counter_id, suffix_4_test).get_len(storage)
+    }
+    pub fn get_user_entries<'a>(storage: &dyn Storage, counter_id: &str, user_addr: Addr, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
         let entry_store = if let Some(suffix) = suffix_4_test {
             &(USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.add_suffix(suffix))
         } else {
             &USER_COUNT_UPDATE_HISTORY_ENTRY_STORE
         };
 
-        let user_addr_index = UserCountUpdateHistoryManager::get_user_addr_specific_index(user_addr);
+        let user_addr_index = UserCountUpdateHistoryManager::get_user_addr_specific_index(counter_id, user_addr);
         let items = if reverse_order {
             keyset_reverse_paging(&user_addr_index, storage, page_zero_based, page_size)
         }
@@ -92,22 +127,18 @@ impl UserCountUpdateHistoryManager {
             map(|id| entry_store.get(storage, id).unwrap()).
             collect::<Vec<UserCountUpdateHistoryEntry>>()
     }
-    pub fn get_user_entries_total_count(storage: &dyn Storage, user_addr: Addr) -> StdResult<u32> {
-        let user_addr_index = UserCountUpdateHistoryManager::get_user_addr_specific_index(user_addr);
+    pub fn get_user_entries_total_count(storage: &dyn Storage, counter_id: &str, user_addr: Addr) -> StdResult<u32> {
+        let user_addr_index = UserCountUpdateHistoryManager::get_user_addr_specific_index(counter_id, user_addr);
 
         user_addr_index.get_len(storage)
     }
-    pub fn get_public_entries<'a>(storage: &dyn Storage, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
+    pub fn get_public_entries<'a>(storage: &dyn Storage, counter_id: &str, page_zero_based: u32, page_size: u32, reverse_order: bool, suffix_4_test: Option<&[u8]>) -> Vec<UserCountUpdateHistoryEntry> {
         let entry_store = if let Some(suffix) = suffix_4_test {
             &(USER_COUNT_UPDATE_HISTORY_ENTRY_STORE.add_suffix(suffix))
         } else {
             &USER_COUNT_UPDATE_HISTORY_ENTRY_STORE
         };
-        let index_store = if let Some(suffix) = suffix_4_test {
-            &(GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(suffix))
-        } else {
-            &GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE
-        };
+        let index_store = UserCountUpdateHistoryManager::get_counter_specific_public_index(counter_id, suffix_4_test);
 
         let items = if reverse_order {
             keyset_reverse_paging(&index_store, storage, page_zero_based, page_size)
@@ -119,18 +150,28 @@ impl UserCountUpdateHistoryManager {
             map(|id| entry_store.get(storage, id).unwrap()).
             collect::<Vec<UserCountUpdateHistoryEntry>>()
     }
-    pub fn get_public_entries_total_count(storage: &dyn Storage, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
-        let index_store = if let Some(suffix) = suffix_4_test {
-            &(GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(suffix))
-        } else {
-            &GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE
-        };
-
-        index_store.get_len(storage)
+    pub fn get_public_entries_total_count(storage: &dyn Storage, counter_id: &str, suffix_4_test: Option<&[u8]>) -> StdResult<u32> {
+        UserCountUpdateHistoryManager::get_counter_specific_public_index(counter_id, suffix_4_test).get_len(storage)
     }
 
-    fn get_user_addr_specific_index<'a>(user_addr: Addr) -> Keyset<'a, String> {
-        USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(user_addr.as_bytes())
+    fn get_user_addr_specific_index<'a>(counter_id: &str, user_addr: Addr) -> Keyset<'a, String> {
+        USER_ADDR_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes()).add_suffix(user_addr.as_bytes())
+    }
+    fn get_counter_specific_index<'a>(counter_id: &str, suffix_4_test: Option<&[u8]>) -> Keyset<'a, String> {
+        let index_store = COUNTER_ID_TO_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes());
+        if let Some(suffix) = suffix_4_test {
+            index_store.add_suffix(suffix)
+        } else {
+            index_store
+        }
+    }
+    fn get_counter_specific_public_index<'a>(counter_id: &str, suffix_4_test: Option<&[u8]>) -> Keyset<'a, String> {
+        let index_store = GLOBAL_PUBLIC_USER_COUNT_UPDATE_HISTORY_INDEX_STORE.add_suffix(counter_id.as_bytes());
+        if let Some(suffix) = suffix_4_test {
+            index_store.add_suffix(suffix)
+        } else {
+            index_store
+        }
     }
 }
 
@@ -153,6 +194,7 @@ mod tests {
     use cosmwasm_std::{StdResult};
     use cosmwasm_std::testing::*;
     use nanoid::nanoid;
+    use crate::state::counters::DEFAULT_COUNTER_ID;
 
     #[test]
     fn data_store_with_keymap_works() -> StdResult<()> {
@@ -168,23 +210,29 @@ mod tests {
         // save + load
         assert!(store.insert(deps.as_mut().storage, &key.clone(), &UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 1,
+            count_change: Int64::new(1),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         }).is_ok());
         assert_eq!(store.get(deps.as_ref().storage, &key.clone()), Some(UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 1,
+            count_change: Int64::new(1),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         }));
         // update
         let mut state = store.get(deps.as_ref().storage, &key.clone()).unwrap();
-        state.count_change += 2;
+        state.count_change = Int64::new(state.count_change.i64() + 2);
         assert!(store.insert(deps.as_mut().storage, &key.clone(), &state).is_ok());
         assert_eq!(store.get(deps.as_ref().storage, &key.clone()), Some(UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 3,
+            count_change: Int64::new(3),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         }));
@@ -210,46 +258,56 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: Some(Timestamp::from_nanos(0)),
             }),
             (&key, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: Some(Timestamp::from_nanos(0)),
             }),
         ];
         entries.iter().for_each(|entry| {
-            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.1.clone(), Some(suffix_4_test)).unwrap()
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.1.clone(), Some(suffix_4_test)).unwrap()
         });
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_public_entries(deps.as_ref().storage, 0, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_public_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 0, 2, true, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 3,
+                    count_change: Int64::new(3),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: Some(Timestamp::from_nanos(0)),
                 },
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: Some(Timestamp::from_nanos(0)),
                 },
             ],
         );
         assert_eq!(
-            UserCountUpdateHistoryManager::get_public_entries(deps.as_ref().storage, 1, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_public_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 1, 2, true, Some(suffix_4_test)),
             vec![],
         );
 
@@ -273,19 +331,25 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key1, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key2, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key3, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
@@ -300,19 +364,25 @@ mod tests {
         // Probably in order until stuff got deleted, see KeySet
         assert_eq!(x.next().unwrap()?.1, UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 1,
+            count_change: Int64::new(1),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         });
         assert_eq!(x.next().unwrap()?.1, UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 2,
+            count_change: Int64::new(2),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         });
         assert_eq!(x.next().unwrap()?.1, UserCountUpdateHistoryEntry{
             user_addr: user_addr.clone(),
-            count_change: 3,
+            count_change: Int64::new(3),
+            resulting_count: Some(Int64::new(0)),
+            kind: UserCountUpdateHistoryEntryKind::Increment,
             created_at: Default::default(),
             marked_as_public_at: None,
         });
@@ -353,51 +423,63 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key1, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key2, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key3, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
         ];
         entries.iter().for_each(|entry| {
             // save + load
-            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.1.clone(), Some(suffix_4_test)).unwrap()
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.1.clone(), Some(suffix_4_test)).unwrap()
         });
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, user_addr.clone(), 0, 2, false, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone(), 0, 2, false, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
             ],
         );
         assert_eq!(
-            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, user_addr.clone(), 1, 2, false, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone(), 1, 2, false, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 3,
+                    count_change: Int64::new(3),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
@@ -406,7 +488,7 @@ mod tests {
 
         // Count
         assert_eq!(
-            UserCountUpdateHistoryManager::get_user_entries_total_count(deps.as_ref().storage, user_addr.clone())?,
+            UserCountUpdateHistoryManager::get_user_entries_total_count(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone())?,
             3,
         );
 
@@ -431,51 +513,63 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key1, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key2, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key3, UserCountUpdateHistoryEntry{
                 user_addr: user_addr.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
         ];
         entries.iter().for_each(|entry| {
             // save + load
-            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.1.clone(), Some(suffix_4_test)).unwrap()
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.1.clone(), Some(suffix_4_test)).unwrap()
         });
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, user_addr.clone(), 0, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone(), 0, 2, true, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 3,
+                    count_change: Int64::new(3),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
             ],
         );
         assert_eq!(
-            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, user_addr.clone(), 1, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_user_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, user_addr.clone(), 1, 2, true, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr.clone(),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
@@ -505,51 +599,63 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key1, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_1.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key2, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_2.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key3, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_3.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
         ];
         entries.iter().for_each(|entry| {
             // save + load
-            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.1.clone(), Some(suffix_4_test)).unwrap()
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.1.clone(), Some(suffix_4_test)).unwrap()
         });
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, 0, 2, false, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 0, 2, false, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_1.clone(),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_2.clone(),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
             ],
         );
         assert_eq!(
-            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, 1, 2, false, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 1, 2, false, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_3.clone(),
-                    count_change: 3,
+                    count_change: Int64::new(3),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
@@ -558,10 +664,21 @@ mod tests {
 
         // Count
         assert_eq!(
-            UserCountUpdateHistoryManager::get_global_entries_total_count(deps.as_ref().storage, Some(suffix_4_test))?,
+            UserCountUpdateHistoryManager::get_global_entries_total_count(deps.as_ref().storage, DEFAULT_COUNTER_ID, Some(suffix_4_test))?,
             3,
         );
 
+        // Entries of other counters not included
+        UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, "other_counter", entries[0].1.clone(), Some(suffix_4_test))?;
+        assert_eq!(
+            UserCountUpdateHistoryManager::get_global_entries_total_count(deps.as_ref().storage, DEFAULT_COUNTER_ID, Some(suffix_4_test))?,
+            3,
+        );
+        assert_eq!(
+            UserCountUpdateHistoryManager::get_user_entries_total_count(deps.as_ref().storage, "other_counter", user_addr_1.clone())?,
+            1,
+        );
+
         Ok(())
     }
 
@@ -585,51 +702,63 @@ mod tests {
         let entries: Vec<(&String, UserCountUpdateHistoryEntry)> = vec![
             (&key1, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_1.clone(),
-                count_change: 1,
+                count_change: Int64::new(1),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key2, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_2.clone(),
-                count_change: 2,
+                count_change: Int64::new(2),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
             (&key3, UserCountUpdateHistoryEntry{
                 user_addr: user_addr_3.clone(),
-                count_change: 3,
+                count_change: Int64::new(3),
+                resulting_count: Some(Int64::new(0)),
+                kind: UserCountUpdateHistoryEntryKind::Increment,
                 created_at: Default::default(),
                 marked_as_public_at: None,
             }),
         ];
         entries.iter().for_each(|entry| {
             // save + load
-            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, entry.1.clone(), Some(suffix_4_test)).unwrap()
+            UserCountUpdateHistoryManager::add_entry(deps.as_mut().storage, &env, DEFAULT_COUNTER_ID, entry.1.clone(), Some(suffix_4_test)).unwrap()
         });
 
         assert_eq!(
-            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, 0, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 0, 2, true, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_3.clone(),
-                    count_change: 3,
+                    count_change: Int64::new(3),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_2.clone(),
-                    count_change: 2,
+                    count_change: Int64::new(2),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
             ],
         );
         assert_eq!(
-            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, 1, 2, true, Some(suffix_4_test)),
+            UserCountUpdateHistoryManager::get_global_entries(deps.as_ref().storage, DEFAULT_COUNTER_ID, 1, 2, true, Some(suffix_4_test)),
             vec![
                 UserCountUpdateHistoryEntry{
                     user_addr: user_addr_1.clone(),
-                    count_change: 1,
+                    count_change: Int64::new(1),
+                    resulting_count: Some(Int64::new(0)),
+                    kind: UserCountUpdateHistoryEntryKind::Increment,
                     created_at: Default::default(),
                     marked_as_public_at: None,
                 },
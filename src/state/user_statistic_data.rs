@@ -1,15 +1,30 @@
-use cosmwasm_std::{Addr};
+use cosmwasm_std::{Addr, Timestamp, Uint64};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use secret_toolkit::storage::{Keymap};
 use secret_toolkit::serialization::{Json};
 
+// Counter ID as suffix, see `get_counter_specific_store`
 pub static USER_STATISTIC_DATA_STORE: Keymap<Addr, UserStatisticData, Json> = Keymap::new(b"user_statistic_data");
 
+pub fn get_counter_specific_store<'a>(counter_id: &str) -> Keymap<'a, Addr, UserStatisticData, Json> {
+    USER_STATISTIC_DATA_STORE.add_suffix(counter_id.as_bytes())
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
 pub struct UserStatisticData {
-    pub count_increment_count: u32,
+    pub count_increment_count: Uint64,
+}
+
+// Used for rate limiting only, not exposed in queries
+pub static USER_ACTION_TIMESTAMPS_STORE: Keymap<Addr, UserActionTimestamps, Json> = Keymap::new(b"user_action_timestamps");
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema, Default)]
+pub struct UserActionTimestamps {
+    pub last_incremented_at: Option<Timestamp>,
+    pub bookmark_window_started_at: Option<Timestamp>,
+    pub bookmarks_created_in_window: u32,
 }
 
 
@@ -28,13 +43,13 @@ mod tests {
         // is_empty
         assert_eq!(store.is_empty(deps.as_ref().storage), Ok(true));
         // save + load
-        assert!(store.insert(deps.as_mut().storage, &Addr::unchecked(key), &UserStatisticData{count_increment_count: 1}).is_ok());
-        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key)), Some(UserStatisticData{count_increment_count: 1}));
+        assert!(store.insert(deps.as_mut().storage, &Addr::unchecked(key), &UserStatisticData{count_increment_count: Uint64::new(1)}).is_ok());
+        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key)), Some(UserStatisticData{count_increment_count: Uint64::new(1)}));
         // update
         let mut state = store.get(deps.as_ref().storage, &Addr::unchecked(key)).unwrap();
-        state.count_increment_count += 2;
+        state.count_increment_count += Uint64::new(2);
         assert!(store.insert(deps.as_mut().storage, &Addr::unchecked(key), &state).is_ok());
-        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key)), Some(UserStatisticData{count_increment_count: 3}));
+        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key)), Some(UserStatisticData{count_increment_count: Uint64::new(3)}));
         // remove
         store.remove(deps.as_mut().storage, &Addr::unchecked(key))?;
         assert!(store.is_empty(deps.as_ref().storage)?);
@@ -52,22 +67,22 @@ mod tests {
 
         assert_eq!(store.is_empty(deps.as_ref().storage), Ok(true));
         // save + load
-        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key1.clone()), &UserStatisticData{count_increment_count: 1}).is_ok(), true);
-        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key1.clone())), Some(UserStatisticData{count_increment_count: 1}));
+        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key1.clone()), &UserStatisticData{count_increment_count: Uint64::new(1)}).is_ok(), true);
+        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key1.clone())), Some(UserStatisticData{count_increment_count: Uint64::new(1)}));
 
         // save + load
-        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key2.clone()), &UserStatisticData{count_increment_count: 2}).is_ok(), true);
-        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key2.clone())), Some(UserStatisticData{count_increment_count: 2}));
+        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key2.clone()), &UserStatisticData{count_increment_count: Uint64::new(2)}).is_ok(), true);
+        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key2.clone())), Some(UserStatisticData{count_increment_count: Uint64::new(2)}));
 
         // save + load
-        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key3.clone()), &UserStatisticData{count_increment_count: 3}).is_ok(), true);
-        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key3.clone())), Some(UserStatisticData{count_increment_count: 3}));
+        assert_eq!(store.insert(deps.as_mut().storage, &Addr::unchecked(key3.clone()), &UserStatisticData{count_increment_count: Uint64::new(3)}).is_ok(), true);
+        assert_eq!(store.get(deps.as_ref().storage, &Addr::unchecked(key3.clone())), Some(UserStatisticData{count_increment_count: Uint64::new(3)}));
 
         let mut x = store.iter(deps.as_ref().storage)?;
         // Probably in order until stuff got deleted, see KeySet
-        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: 1});
-        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: 2});
-        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: 3});
+        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: Uint64::new(1)});
+        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: Uint64::new(2)});
+        assert_eq!(x.next().unwrap()?.1, UserStatisticData{count_increment_count: Uint64::new(3)});
         assert_eq!(x.next().is_none(), true);
 
         // Test can will iterate after deleting some stuff
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Env, StdError, StdResult, Storage};
+use cosmwasm_std::{Addr, Binary, Env, StdError, StdResult, Storage};
 use serde::{Serialize};
 
 use secret_toolkit::storage::{Keyset, Keymap};
@@ -6,6 +6,7 @@ use secret_toolkit::serialization::{Serde};
 use secret_toolkit::storage::iter_options::{WithIter};
 use serde::de::DeserializeOwned;
 use ulid::Ulid;
+use secret_toolkit::crypto::{sha_256, ContractPrng};
 
 /// `paging` method only present for ascend order
 pub fn keyset_reverse_paging<'a, K, Ser>(keyset: &Keyset<'a, K, Ser, WithIter>, storage: &dyn Storage, start_page: u32, size: u32) -> StdResult<Vec<K>>
@@ -61,3 +62,22 @@ pub fn get_generated_ulid(id_u64: u64, env: &Env) -> StdResult<String> {
     let ulid = Ulid::from_parts(env.block.time.nanos() / 1_000_000, random_u128 + id_u64 as u128);
     Ok(ulid.to_string())
 }
+
+/// Random key from block data, address and user provided entropy
+pub fn generate_random_key(env: &Env, addr: &Addr, entropy: &[u8], prefix: &str) -> String {
+    let mut seed = Vec::new();
+    seed.extend_from_slice(&env.block.height.to_be_bytes());
+    seed.extend_from_slice(&env.block.time.nanos().to_be_bytes());
+    seed.extend_from_slice(addr.as_bytes());
+    if let Some(random) = &env.block.random {
+        seed.extend_from_slice(random.as_slice());
+    }
+
+    let mut rng = ContractPrng::new(&seed, entropy);
+    format!("{}{}", prefix, Binary::from(sha_256(&rng.rand_bytes())).to_base64())
+}
+
+/// For comparing key hashes without leaking info via timing
+pub fn constant_time_eq(a: &[u8; 32], b: &[u8; 32]) -> bool {
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,67 @@
+use cosmwasm_std::{Addr, Env, StdError, StdResult, Storage};
+use secret_toolkit::crypto::{sha_256};
+use secret_toolkit::storage::{Keymap};
+
+use crate::state::utils::{constant_time_eq, generate_random_key};
+
+pub const VIEWING_KEY_PREFIX: &str = "api_key_";
+
+// Only hashes are stored
+static VIEWING_KEY_HASH_STORE: Keymap<Addr, [u8; 32]> = Keymap::new(b"viewing_keys__key_hash");
+
+
+#[derive(Default)]
+pub struct ViewingKeysManager{}
+impl ViewingKeysManager {
+    /// Generates a new key from block data, sender and user provided entropy, then saves it
+    pub fn create(storage: &mut dyn Storage, env: &Env, addr: &Addr, entropy: &str) -> StdResult<String> {
+        let key = generate_random_key(env, addr, entropy.as_bytes(), VIEWING_KEY_PREFIX);
+        ViewingKeysManager::set(storage, addr, &key)?;
+
+        Ok(key)
+    }
+
+    pub fn set(storage: &mut dyn Storage, addr: &Addr, key: &str) -> StdResult<()> {
+        VIEWING_KEY_HASH_STORE.insert(storage, addr, &sha_256(key.as_bytes()))
+    }
+
+    pub fn check(storage: &dyn Storage, addr: &Addr, key: &str) -> StdResult<()> {
+        let key_hash = sha_256(key.as_bytes());
+        // Compare against dummy hash when not set, so timing does not reveal whether a key exists
+        let stored_key_hash = VIEWING_KEY_HASH_STORE.get(storage, addr);
+        let is_match = constant_time_eq(&key_hash, &stored_key_hash.unwrap_or([0u8; 32]));
+        if stored_key_hash.is_none() || !is_match {
+            return Err(StdError::generic_err("Wrong viewing key for this address or viewing key not set"));
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::*;
+
+    #[test]
+    fn test_viewing_keys() -> StdResult<()> {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let addr = Addr::unchecked("user1");
+
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &addr, "").is_err());
+
+        let key = ViewingKeysManager::create(deps.as_mut().storage, &env, &addr, "entropy")?;
+        assert!(key.starts_with(VIEWING_KEY_PREFIX));
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &addr, &key).is_ok());
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &Addr::unchecked("user2"), &key).is_err());
+
+        // Replaced by the new one
+        ViewingKeysManager::set(deps.as_mut().storage, &addr, "my_key")?;
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &addr, &key).is_err());
+        assert!(ViewingKeysManager::check(deps.as_ref().storage, &addr, "my_key").is_ok());
+
+        Ok(())
+    }
+}
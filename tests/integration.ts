@@ -152,7 +152,8 @@ async function queryCount(
   contractHash: string,
   contractAddress: string
 ): Promise<number> {
-  type CountResponse = { count: number };
+  // 64-bit integers are strings in JSON
+  type CountResponse = { count: string };
 
   const countResponse = (await client.query.compute.queryContract({
     contract_address: contractAddress,
@@ -166,7 +167,7 @@ async function queryCount(
     );
   }
 
-  return countResponse.count;
+  return Number(countResponse.count);
 }
 
 async function incrementTx(